@@ -0,0 +1,463 @@
+//! Points and vectors on integer grids of any dimension, with the directions
+//! and bounding boxes the challenges keep needing.
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Point or vector on an `N`-dimensional integer grid.
+///
+/// On 2D grids `x` grows to the right and `y` grows downwards, like the rows
+/// of a puzzle input, so [`Direction::Up`] is towards negative `y`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Coord<const N: usize>(pub [i32; N]);
+
+pub type Coord2 = Coord<2>;
+pub type Coord3 = Coord<3>;
+
+impl<const N: usize> Coord<N> {
+    /// The origin.
+    pub const ZERO: Coord<N> = Coord([0; N]);
+
+    /// Coordinate with all components set to `value`.
+    pub const fn splat(value: i32) -> Coord<N> {
+        Coord([value; N])
+    }
+
+    /// Unit vector along `axis`.
+    pub fn unit(axis: usize) -> Coord<N> {
+        let mut unit = Coord::ZERO;
+        unit.0[axis] = 1;
+        unit
+    }
+
+    /// Apply `f` to every component.
+    pub fn map(self, f: impl FnMut(i32) -> i32) -> Coord<N> {
+        Coord(self.0.map(f))
+    }
+
+    /// Combine the components of `self` and `other` with `f`.
+    pub fn zip_with(mut self, other: Coord<N>, mut f: impl FnMut(i32, i32) -> i32) -> Coord<N> {
+        self.0.iter_mut().zip(other.0).for_each(|(l, r)| *l = f(*l, r));
+        self
+    }
+
+    pub fn abs(self) -> Coord<N> {
+        self.map(i32::abs)
+    }
+
+    /// Sign of every component, i.e. a step of at most one along every axis
+    /// in the direction of this vector.
+    pub fn signum(self) -> Coord<N> {
+        self.map(i32::signum)
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Coord<N>) -> Coord<N> {
+        self.zip_with(other, i32::min)
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Coord<N>) -> Coord<N> {
+        self.zip_with(other, i32::max)
+    }
+
+    /// Length of the vector in the taxicab metric.
+    pub fn manhattan_length(self) -> i32 {
+        self.0.iter().map(|c| c.abs()).sum()
+    }
+
+    /// Length of the vector in the chessboard metric.
+    pub fn chebyshev_length(self) -> i32 {
+        self.0.iter().map(|c| c.abs()).max().unwrap_or(0)
+    }
+
+    /// Taxicab distance to `other`: the number of orthogonal steps to get there.
+    pub fn manhattan(self, other: Coord<N>) -> i32 {
+        (self - other).manhattan_length()
+    }
+
+    /// Chessboard distance to `other`: the number of steps to get there when
+    /// diagonal steps are allowed.
+    pub fn chebyshev(self, other: Coord<N>) -> i32 {
+        (self - other).chebyshev_length()
+    }
+
+    /// The `2 * N` coordinates one step away along a single axis, e.g. the 4
+    /// neighbours on a 2D grid.
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item = Coord<N>> {
+        (0..2 * N).map(move |i| {
+            let mut neighbour = self;
+            neighbour.0[i / 2] += if i % 2 == 0 { -1 } else { 1 };
+            neighbour
+        })
+    }
+
+    /// The `3^N - 1` coordinates one step away when diagonal steps are
+    /// allowed, e.g. the 8 neighbours on a 2D grid.
+    pub fn neighbours(self) -> impl Iterator<Item = Coord<N>> {
+        let count = 3_usize.pow(N as u32);
+        (0..count).filter(move |&i| i != count / 2).map(move |mut i| {
+            let mut neighbour = self;
+            for c in &mut neighbour.0 {
+                *c += (i % 3) as i32 - 1;
+                i /= 3;
+            }
+            neighbour
+        })
+    }
+
+    /// Rotate a quarter turn in the plane of axes `from` and `to`, turning the
+    /// unit vector along `from` into the one along `to`.
+    pub fn rotate(mut self, from: usize, to: usize) -> Coord<N> {
+        let (a, b) = (self.0[from], self.0[to]);
+        self.0[from] = -b;
+        self.0[to] = a;
+        self
+    }
+}
+
+impl Coord<2> {
+    pub const fn new(x: i32, y: i32) -> Coord<2> {
+        Coord([x, y])
+    }
+
+    pub const fn x(self) -> i32 {
+        self.0[0]
+    }
+
+    pub const fn y(self) -> i32 {
+        self.0[1]
+    }
+
+    /// Rotate a quarter turn clockwise around the origin, as seen on screen.
+    pub fn rotate_right(self) -> Coord<2> {
+        self.rotate(0, 1)
+    }
+
+    /// Rotate a quarter turn counterclockwise around the origin, as seen on
+    /// screen.
+    pub fn rotate_left(self) -> Coord<2> {
+        self.rotate(1, 0)
+    }
+}
+
+impl Coord<3> {
+    pub const fn new(x: i32, y: i32, z: i32) -> Coord<3> {
+        Coord([x, y, z])
+    }
+
+    pub const fn x(self) -> i32 {
+        self.0[0]
+    }
+
+    pub const fn y(self) -> i32 {
+        self.0[1]
+    }
+
+    pub const fn z(self) -> i32 {
+        self.0[2]
+    }
+}
+
+impl<const N: usize> Default for Coord<N> {
+    fn default() -> Coord<N> {
+        Coord::ZERO
+    }
+}
+
+impl<const N: usize> From<[i32; N]> for Coord<N> {
+    fn from(components: [i32; N]) -> Coord<N> {
+        Coord(components)
+    }
+}
+
+impl<const N: usize> Index<usize> for Coord<N> {
+    type Output = i32;
+
+    fn index(&self, axis: usize) -> &i32 {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Coord<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut i32 {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add<Coord<N>> for Coord<N> {
+    type Output = Coord<N>;
+
+    fn add(mut self, rhs: Coord<N>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const N: usize> AddAssign<Coord<N>> for Coord<N> {
+    fn add_assign(&mut self, rhs: Coord<N>) {
+        self.0.iter_mut().zip(&rhs.0).for_each(|(l, r)| *l += r);
+    }
+}
+
+impl<const N: usize> Sub<Coord<N>> for Coord<N> {
+    type Output = Coord<N>;
+
+    fn sub(mut self, rhs: Coord<N>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const N: usize> SubAssign<Coord<N>> for Coord<N> {
+    fn sub_assign(&mut self, rhs: Coord<N>) {
+        self.0.iter_mut().zip(&rhs.0).for_each(|(l, r)| *l -= r);
+    }
+}
+
+impl<const N: usize> Mul<i32> for Coord<N> {
+    type Output = Coord<N>;
+
+    fn mul(mut self, rhs: i32) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<const N: usize> MulAssign<i32> for Coord<N> {
+    fn mul_assign(&mut self, rhs: i32) {
+        self.0.iter_mut().for_each(|c| *c *= rhs);
+    }
+}
+
+impl<const N: usize> Neg for Coord<N> {
+    type Output = Coord<N>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|c| -c)
+    }
+}
+
+impl<const N: usize> Display for Coord<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// One of the four orthogonal directions on a 2D grid.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting at [`Direction::Up`].
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// Step of one in this direction.
+    pub const fn offset(self) -> Coord<2> {
+        match self {
+            Direction::Up => Coord([0, -1]),
+            Direction::Right => Coord([1, 0]),
+            Direction::Down => Coord([0, 1]),
+            Direction::Left => Coord([-1, 0]),
+        }
+    }
+
+    /// The direction after a quarter turn clockwise.
+    pub const fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction after a quarter turn counterclockwise.
+    pub const fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub const fn opposite(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the eight points of the compass on a 2D grid, north being up.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Compass {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Compass {
+    /// All points of the compass, clockwise starting at [`Compass::N`].
+    pub const ALL: [Compass; 8] = [Compass::N, Compass::NE, Compass::E, Compass::SE, Compass::S, Compass::SW, Compass::W, Compass::NW];
+
+    /// Step of one in this direction, diagonally for the intercardinal points.
+    pub const fn offset(self) -> Coord<2> {
+        match self {
+            Compass::N => Coord([0, -1]),
+            Compass::NE => Coord([1, -1]),
+            Compass::E => Coord([1, 0]),
+            Compass::SE => Coord([1, 1]),
+            Compass::S => Coord([0, 1]),
+            Compass::SW => Coord([-1, 1]),
+            Compass::W => Coord([-1, 0]),
+            Compass::NW => Coord([-1, -1]),
+        }
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Compass {
+        Compass::ALL[direction as usize * 2]
+    }
+}
+
+/// Smallest axis-aligned box containing a set of coordinates, including both
+/// corners.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Bounds<const N: usize> {
+    pub min: Coord<N>,
+    pub max: Coord<N>,
+}
+
+impl<const N: usize> Bounds<N> {
+    /// Box containing only `point`.
+    pub fn new(point: Coord<N>) -> Bounds<N> {
+        Bounds { min: point, max: point }
+    }
+
+    /// Bounding box of `points`, or `None` if there are none.
+    pub fn of(points: impl IntoIterator<Item = Coord<N>>) -> Option<Bounds<N>> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Bounds::new(first), |bounds, p| bounds.including(p)))
+    }
+
+    /// This box, grown to contain `point`.
+    pub fn including(self, point: Coord<N>) -> Bounds<N> {
+        Bounds { min: self.min.min(point), max: self.max.max(point) }
+    }
+
+    /// This box, grown by `by` on every side.
+    pub fn grow(self, by: i32) -> Bounds<N> {
+        Bounds { min: self.min - Coord::splat(by), max: self.max + Coord::splat(by) }
+    }
+
+    pub fn contains(&self, point: Coord<N>) -> bool {
+        (0..N).all(|i| (self.min.0[i]..=self.max.0[i]).contains(&point.0[i]))
+    }
+
+    /// Number of coordinates along every axis.
+    pub fn size(&self) -> Coord<N> {
+        self.max - self.min + Coord::splat(1)
+    }
+
+    /// Number of coordinates in the box.
+    pub fn volume(&self) -> u64 {
+        self.size().0.iter().map(|&s| s as u64).product()
+    }
+
+    /// All coordinates in the box, with the first axis changing fastest, so on
+    /// a 2D grid row by row.
+    pub fn points(self) -> impl Iterator<Item = Coord<N>> {
+        let mut next = Some(self.min);
+        std::iter::from_fn(move || {
+            let point = next?;
+
+            // Count up like an odometer, the first axis being the last digit
+            let mut following = point;
+            next = (0..N).find_map(|i| match following.0[i] < self.max.0[i] {
+                true => {
+                    following.0[i] += 1;
+                    Some(following)
+                },
+                false => {
+                    following.0[i] = self.min.0[i];
+                    None
+                },
+            });
+            Some(point)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let (a, b) = (Coord2::new(1, -2), Coord2::new(-3, 4));
+        assert_eq!(a + b, Coord2::new(-2, 2));
+        assert_eq!(a - b, Coord2::new(4, -6));
+        assert_eq!(-a * 2, Coord2::new(-2, 4));
+        assert_eq!((a - b).signum(), Coord2::new(1, -1));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Coord::<3>::new(1, 2, 3).to_string(), "(1, 2, 3)");
+    }
+
+    #[test]
+    fn neighbours() {
+        let mut orthogonal = Coord2::new(5, 5).orthogonal_neighbours().collect::<Vec<Coord2>>();
+        let mut expected = Direction::ALL.map(|d| Coord2::new(5, 5) + d.offset()).to_vec();
+        orthogonal.sort();
+        expected.sort();
+        assert_eq!(orthogonal, expected);
+
+        let mut all = Coord2::ZERO.neighbours().collect::<Vec<Coord2>>();
+        let mut expected = Compass::ALL.map(Compass::offset).to_vec();
+        all.sort();
+        expected.sort();
+        assert_eq!(all, expected);
+
+        assert_eq!(Coord3::ZERO.orthogonal_neighbours().count(), 6);
+        assert_eq!(Coord3::ZERO.neighbours().count(), 26);
+        assert!(Coord3::ZERO.neighbours().all(|n| n.chebyshev_length() == 1));
+    }
+
+    #[test]
+    fn directions_and_rotation() {
+        for d in Direction::ALL {
+            assert_eq!(d.offset().rotate_right(), d.turn_right().offset());
+            assert_eq!(d.offset().rotate_left(), d.turn_left().offset());
+            assert_eq!(-d.offset(), d.opposite().offset());
+            assert_eq!(Compass::from(d).offset(), d.offset());
+        }
+
+        // A quarter turn around the z axis, and back
+        let p = Coord3::new(1, 2, 3);
+        assert_eq!(p.rotate(0, 1), Coord3::new(-2, 1, 3));
+        assert_eq!(p.rotate(0, 1).rotate(1, 0), p);
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        let bounds = Bounds::of([Coord2::new(2, 1), Coord2::new(0, 3), Coord2::new(1, 2)]).unwrap();
+        assert_eq!(bounds, Bounds { min: Coord2::new(0, 1), max: Coord2::new(2, 3) });
+        assert_eq!((bounds.size(), bounds.volume()), (Coord2::new(3, 3), 9));
+        assert!(bounds.contains(Coord2::new(1, 1)) && !bounds.contains(Coord2::new(3, 1)));
+        assert_eq!(bounds.grow(1).volume(), 25);
+        assert_eq!(Bounds::<2>::of([]), None);
+
+        let points = bounds.points().collect::<Vec<Coord2>>();
+        assert_eq!(points.len(), 9);
+        assert_eq!(&points[..4], [Coord2::new(0, 1), Coord2::new(1, 1), Coord2::new(2, 1), Coord2::new(0, 2)]);
+        assert_eq!(Bounds::new(Coord3::ZERO).grow(1).points().count(), 27);
+    }
+}
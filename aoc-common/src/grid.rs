@@ -0,0 +1,251 @@
+//! Rectangular grids of cells, sized at runtime, like the maps many puzzle
+//! inputs consist of.
+
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::{Coord2, ParseError};
+
+/// Rectangular grid of cells, stored row by row.
+///
+/// Cells are addressed by [`Coord2`], `x` being the column and `y` the row, or
+/// by `(x, y)` pairs of indices.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` by `height` cells, all set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where T: Clone {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Grid of rows of `width` cells, from all cells row by row.
+    ///
+    /// Panics if the number of cells is not a multiple of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(width > 0 && cells.len().is_multiple_of(width), "{} cells do not make rows of {}", cells.len(), width);
+        Grid { width, height: cells.len() / width, cells }
+    }
+
+    /// Parse a map with a character per cell and a line per row, converting
+    /// the characters with `cell`. Fails at the first character `cell` rejects,
+    /// reporting that `expected` was expected there, and at rows that are not
+    /// as wide as the first.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>, expected: &str) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+
+        for line in input.lines() {
+            let mut count = 0;
+            for (i, c) in line.char_indices() {
+                cells.push(cell(c).ok_or_else(|| ParseError::at(input, &line[i..], expected))?);
+                count += 1;
+            }
+
+            match width {
+                None => width = Some(count),
+                Some(width) if count != width => {
+                    let at = line.char_indices().nth(width).map_or(line.len(), |(i, _)| i);
+                    return Err(ParseError::at(input, &line[at..], format!("a row of {} cells", width)));
+                },
+                Some(_) => (),
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid::from_cells(width, cells)),
+            _ => Err(ParseError::at_end(input, expected)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Whether `pos` is on the grid.
+    pub fn contains(&self, pos: Coord2) -> bool {
+        (0..self.width as i32).contains(&pos.x()) && (0..self.height as i32).contains(&pos.y())
+    }
+
+    /// Index of the cell at `pos` in the cells row by row, if it is on the grid.
+    pub fn index_of(&self, pos: Coord2) -> Option<usize> {
+        self.contains(pos).then(|| pos.y() as usize * self.width + pos.x() as usize)
+    }
+
+    /// Position of the cell at `index` in the cells row by row.
+    pub fn coord_of(&self, index: usize) -> Coord2 {
+        Coord2::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn get(&self, pos: Coord2) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Coord2) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// All cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Row `y`.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// All rows, from top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds for width {}", x, self.width);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord2, &T)> {
+        self.cells.iter().enumerate().map(|(i, cell)| (self.coord_of(i), cell))
+    }
+
+    /// Positions of the cells orthogonally next to `pos` that are on the grid.
+    pub fn neighbours(&self, pos: Coord2) -> impl Iterator<Item = Coord2> + '_ {
+        pos.orthogonal_neighbours().filter(|&n| self.contains(n))
+    }
+
+    /// Positions of the cells next to `pos`, also diagonally, that are on the
+    /// grid.
+    pub fn all_neighbours(&self, pos: Coord2) -> impl Iterator<Item = Coord2> + '_ {
+        pos.neighbours().filter(|&n| self.contains(n))
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Coord2> {
+        self.cells.iter().position(predicate).map(|i| self.coord_of(i))
+    }
+
+    /// Grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T> Index<Coord2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Coord2) -> &T {
+        match self.index_of(pos) {
+            Some(i) => &self.cells[i],
+            None => panic!("{} is out of bounds for a {}x{} grid", pos, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Coord2> for Grid<T> {
+    fn index_mut(&mut self, pos: Coord2) -> &mut T {
+        match self.index_of(pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{} is out of bounds for a {}x{} grid", pos, self.width, self.height),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width, "column {} is out of bounds for width {}", x, self.width);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width, "column {} is out of bounds for width {}", x, self.width);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Renders the cells row by row, a line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#.#.\n..##\n#...\n";
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(input, |c| matches!(c, '#' | '.').then_some(c), "`#` or `.`")
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = parse(MAP).unwrap();
+        assert_eq!((grid.width(), grid.height(), grid.len()), (4, 3, 12));
+        assert_eq!(grid.to_string(), MAP);
+        assert_eq!(grid[Coord2::new(3, 1)], '#');
+        assert_eq!(grid[(1, 2)], '.');
+        assert_eq!(grid.get(Coord2::new(4, 0)), None);
+        assert_eq!(grid.get(Coord2::new(0, -1)), None);
+
+        let error = parse("#.#.\n..#x\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 4, "`#` or `.`"));
+        let error = parse("#.#.\n..#\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 4, "a row of 4 cells"));
+        let error = parse("#.#.\n..#..\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn iterators() {
+        let grid = parse(MAP).unwrap();
+        assert_eq!(grid.row(1), ['.', '.', '#', '#']);
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.column(2).collect::<String>(), "##.");
+        assert_eq!(grid.column(3).rev().collect::<String>(), ".#.");
+        assert_eq!(grid.iter().filter(|&(_, &c)| c == '#').count(), 5);
+        assert_eq!(grid.position(|&c| c == '#'), Some(Coord2::new(0, 0)));
+
+        assert_eq!(grid.neighbours(Coord2::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours(Coord2::new(1, 1)).count(), 4);
+        assert_eq!(grid.all_neighbours(Coord2::new(3, 2)).count(), 3);
+        assert_eq!(grid.all_neighbours(Coord2::new(1, 1)).count(), 8);
+
+        let counts = grid.map(|&c| if c == '#' { 1 } else { 0 });
+        assert_eq!(counts.cells().iter().sum::<i32>(), 5);
+        assert_eq!(counts.to_string(), "1010\n0011\n1000\n");
+    }
+}
@@ -0,0 +1,226 @@
+//! Sets of integers stored as sorted, disjoint ranges, for when the sets are
+//! too large to list their members.
+
+use std::fmt::Display;
+use std::ops::Range;
+
+/// Set of integers, stored as the ranges it covers.
+///
+/// Ranges are half-open like [`Range`], so `3..5` covers 3 and 4. They are
+/// kept sorted, and ranges that overlap or touch are merged, so every set has
+/// a single representation.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    /// The ranges covered by this set, in order.
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    /// Add all integers in `range`.
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // Merge with every range that overlaps or touches the new one
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = match &self.ranges[first..last] {
+            [] => range,
+            overlapping => overlapping[0].start.min(range.start)..overlapping[overlapping.len() - 1].end.max(range.end),
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Remove all integers in `range`.
+    pub fn remove(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let last = self.ranges.partition_point(|r| r.start < range.end);
+        if first == last {
+            return;
+        }
+
+        // Keep what sticks out on either side of the removed range
+        let (before, after) = (self.ranges[first].start..range.start, range.end..self.ranges[last - 1].end);
+        let kept = [before, after].into_iter().filter(|r| !r.is_empty());
+        self.ranges.splice(first..last, kept);
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    /// Whether all of `range` is in the set.
+    pub fn contains_range(&self, range: &Range<i64>) -> bool {
+        let i = self.ranges.partition_point(|r| r.end < range.end);
+        range.is_empty() || self.ranges.get(i).is_some_and(|r| r.start <= range.start)
+    }
+
+    /// Whether any of `range` is in the set.
+    pub fn overlaps(&self, range: &Range<i64>) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= range.start);
+        !range.is_empty() && self.ranges.get(i).is_some_and(|r| r.start < range.end)
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|r| (r.end - r.start) as u64).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The ranges between the ranges of the set, in order.
+    pub fn gaps(&self) -> impl Iterator<Item = Range<i64>> + '_ {
+        self.ranges.windows(2).map(|w| w[0].end..w[1].start)
+    }
+
+    /// Integers in either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    /// Integers in both sets.
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // Move on from the range that ends first
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Integers in this set, but not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = self.clone();
+        for range in &other.ranges {
+            difference.remove(range.clone());
+        }
+        difference
+    }
+
+    /// The part of the set within `window`.
+    pub fn clamp(&self, window: Range<i64>) -> IntervalSet {
+        self.intersection(&IntervalSet::from(window))
+    }
+}
+
+impl From<Range<i64>> for IntervalSet {
+    fn from(range: Range<i64>) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> IntervalSet {
+        let mut sorted = iter.into_iter().filter(|r| !r.is_empty()).collect::<Vec<Range<i64>>>();
+        sorted.sort_unstable_by_key(|r| r.start);
+
+        // Merging in order of the starts only ever touches the last range
+        let mut ranges: Vec<Range<i64>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl Extend<Range<i64>> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Range<i64>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|range| self.insert(range));
+    }
+}
+
+impl Display for IntervalSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, r) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}..{}", r.start, r.end)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut s = IntervalSet::new();
+        s.insert(10..20);
+        s.insert(0..5);
+        s.insert(30..40);
+        assert_eq!(s.to_string(), "{0..5, 10..20, 30..40}");
+
+        // Touching ranges merge, and so do all ranges a new one spans
+        s.insert(5..7);
+        s.insert(15..35);
+        assert_eq!(s, set(&[0..7, 10..40]));
+        assert_eq!(s, set(&[10..20, 5..7, 15..35, 0..5, 30..40, 3..3]));
+
+        s.remove(3..12);
+        assert_eq!(s, set(&[0..3, 12..40]));
+        s.remove(20..25);
+        assert_eq!(s, set(&[0..3, 12..20, 25..40]));
+        s.remove(-10..100);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn queries() {
+        let s = set(&[0..5, 10..20]);
+        assert!(s.contains(0) && s.contains(4) && s.contains(10));
+        assert!(!s.contains(5) && !s.contains(-1) && !s.contains(20));
+        assert!(s.contains_range(&(11..20)) && !s.contains_range(&(4..11)));
+        assert!(s.overlaps(&(4..11)) && !s.overlaps(&(5..10)));
+        assert_eq!(s.len(), 15);
+        assert_eq!(s.gaps().collect::<Vec<_>>(), vec![5..10]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let (a, b) = (set(&[0..10, 20..30]), IntervalSet::from(5..25));
+        assert_eq!(a.union(&b), IntervalSet::from(0..30));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..30]));
+        assert_eq!(b.difference(&a), IntervalSet::from(10..20));
+        assert_eq!(a.clamp(8..22), set(&[8..10, 20..22]));
+        assert_eq!(a.intersection(&IntervalSet::new()), IntervalSet::new());
+    }
+}
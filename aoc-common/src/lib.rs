@@ -0,0 +1,16 @@
+//! Code shared between the solutions of the different days.
+
+pub mod coord;
+pub mod grid;
+pub mod interval;
+pub mod parse;
+pub mod search;
+mod solution;
+pub mod voxel;
+
+pub use coord::{Bounds, Compass, Coord, Coord2, Coord3, Direction};
+pub use grid::Grid;
+pub use interval::IntervalSet;
+pub use parse::ParseError;
+pub use solution::{DynSolution, Part, Solution};
+pub use voxel::VoxelSet;
@@ -0,0 +1,120 @@
+//! Helpers for turning a puzzle input into a parsed representation, reporting
+//! where in the input things went wrong.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Error returned when a puzzle input could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input the error is on, starting at 1.
+    pub line: usize,
+    /// Column of the error within its line in characters, starting at 1.
+    pub column: usize,
+    /// Description of what was expected at this position.
+    pub expected: String,
+    /// What was found instead, or `None` at the end of a line or the input.
+    pub found: Option<String>,
+}
+
+/// Maximum number of characters shown of what was found instead.
+const FOUND_LEN: usize = 20;
+
+impl ParseError {
+    /// Error at byte `offset` of `input`.
+    pub fn at_offset(input: &str, offset: usize, expected: impl Into<String>) -> ParseError {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let (before, after) = input.split_at(offset);
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let found = after.lines().next().unwrap_or("");
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: match found.char_indices().nth(FOUND_LEN) {
+                _ if found.is_empty() => None,
+                Some((i, _)) => Some(format!("{}...", &found[..i])),
+                None => Some(found.to_owned()),
+            },
+        }
+    }
+
+    /// Error at the start of `at`, which must be a slice of `input`, like the
+    /// lines returned by [`str::lines`].
+    pub fn at(input: &str, at: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (at.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+        ParseError::at_offset(input, offset, expected)
+    }
+
+    /// Error at the end of `input`.
+    pub fn at_end(input: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at_offset(input, input.len(), expected)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: expected {}", self.line, self.column, self.expected)?;
+        match &self.found {
+            Some(found) => write!(f, ", found `{}`", found),
+            None => write!(f, ", found end of line"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse `s`, a slice of `input`, reporting `expected` at `s` when it fails.
+pub fn from_str<T: FromStr>(input: &str, s: &str, expected: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::at(input, s, expected))
+}
+
+/// Split `s`, a slice of `input`, on the first occurrence of `delimiter`.
+/// Fails at the end of `s` if it does not contain the delimiter.
+pub fn split_once<'a>(input: &str, s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, &s[s.len()..], format!("`{}`", delimiter)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1-2\n3,4\n€5-abc";
+
+    #[test]
+    fn position_in_input() {
+        let line = INPUT.lines().nth(2).unwrap();
+        let error = ParseError::at(INPUT, &line[5..], "a number");
+        assert_eq!((error.line, error.column), (3, 4));
+        assert_eq!(error.found.as_deref(), Some("abc"));
+        assert_eq!(error.to_string(), "line 3, column 4: expected a number, found `abc`");
+    }
+
+    #[test]
+    fn end_of_input() {
+        let error = ParseError::at_end(INPUT, "`,`");
+        assert_eq!((error.line, error.column), (3, 7));
+        assert_eq!(error.to_string(), "line 3, column 7: expected `,`, found end of line");
+    }
+
+    #[test]
+    fn helpers() {
+        let mut lines = INPUT.lines();
+        let first = lines.next().unwrap();
+        assert_eq!(split_once(INPUT, first, "-"), Ok(("1", "2")));
+        assert_eq!(from_str::<u8>(INPUT, &first[2..], "a number"), Ok(2));
+
+        let second = lines.next().unwrap();
+        let error = split_once(INPUT, second, "-").unwrap_err();
+        assert_eq!((error.line, error.column, error.found), (2, 4, None));
+
+        let error = from_str::<u8>(INPUT, second, "a number").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a number, found `3,4`");
+    }
+}
@@ -0,0 +1,294 @@
+//! Shortest path searches over graphs given by a function returning the
+//! neighbours of a node, so graphs never have to be built up front.
+//!
+//! All searches start from any number of sources at once, and stop at the
+//! first node `goal` accepts; pass `|_| false` to search the whole graph.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::cmp::Ordering;
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Outcome of a search: the cost of the cheapest path to every node that was
+/// reached, and the node it was reached from, to reconstruct the paths.
+#[derive(Clone, Debug)]
+pub struct Paths<N, C> {
+    reached: HashMap<N, (C, Option<N>)>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    /// The goal the search stopped at, if it found one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// Cost of the goal the search stopped at, if it found one.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    /// Cost of the cheapest path to `node`, if it was reached.
+    ///
+    /// When the search stopped at a goal, only the costs of the nodes that
+    /// were taken from the queue before it are final.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.reached.get(node).map(|&(cost, _)| cost)
+    }
+
+    /// Cheapest path from a source to `node`, both included, if it was
+    /// reached.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut current = node;
+        while let Some(previous) = &self.reached.get(current)?.1 {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All reached nodes with the cost of the cheapest path to them.
+    pub fn costs(&self) -> impl Iterator<Item = (&N, C)> {
+        self.reached.iter().map(|(node, &(cost, _))| (node, cost))
+    }
+}
+
+/// Breadth-first search from `sources`, where every step costs one.
+pub fn bfs<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = N> {
+    let mut reached = HashMap::new();
+    let mut queue = VecDeque::new();
+    for source in sources {
+        if let Entry::Vacant(entry) = reached.entry(source.clone()) {
+            entry.insert((0, None));
+            queue.push_back((source, 0));
+        }
+    }
+
+    while let Some((node, cost)) = queue.pop_front() {
+        if goal(&node) {
+            return Paths { reached, goal: Some(node) };
+        }
+
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = reached.entry(next.clone()) {
+                entry.insert((cost + 1, Some(node.clone())));
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    Paths { reached, goal: None }
+}
+
+/// Dijkstra's algorithm from `sources`, where `neighbours` returns the
+/// neighbours of a node along with the cost of the step to them. Costs must
+/// not be negative, and `C::default()` must be zero.
+pub fn dijkstra<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where N: Clone + Eq + Hash, C: Copy + Ord + Add<Output = C> + Default, I: IntoIterator<Item = (N, C)> {
+    astar(sources, neighbours, |_| C::default(), goal)
+}
+
+/// A* search from `sources`, like [`dijkstra`], but trying the nodes that
+/// `heuristic` estimates to be closer to a goal first. The path to the goal is
+/// only the cheapest if the heuristic never overestimates the remaining cost,
+/// and is consistent: it does not drop by more than the cost of a step.
+pub fn astar<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where N: Clone + Eq + Hash, C: Copy + Ord + Add<Output = C> + Default, I: IntoIterator<Item = (N, C)> {
+    let mut reached: HashMap<N, (C, Option<N>)> = HashMap::new();
+    let mut queue = PriorityQueue::new();
+    for source in sources {
+        let estimate = heuristic(&source);
+        reached.insert(source.clone(), (C::default(), None));
+        queue.push((source, C::default()), estimate);
+    }
+
+    while let Some(((node, cost), _)) = queue.pop() {
+        // A cheaper path to this node was found after it was queued, so this
+        // entry is stale: the queue never removes or updates entries
+        if reached.get(&node).is_some_and(|&(best, _)| cost > best) {
+            continue;
+        }
+        if goal(&node) {
+            return Paths { reached, goal: Some(node) };
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            let cheaper = match reached.get(&next) {
+                Some(&(best, _)) => next_cost < best,
+                None => true,
+            };
+            if cheaper {
+                reached.insert(next.clone(), (next_cost, Some(node.clone())));
+                let estimate = next_cost + heuristic(&next);
+                queue.push((next, next_cost), estimate);
+            }
+        }
+    }
+
+    Paths { reached, goal: None }
+}
+
+/// Queue handing out the item with the lowest priority first, and among
+/// items with the same priority the one pushed first.
+///
+/// There is no way to lower the priority of an item in the queue. Instead,
+/// push it again with the lower priority and skip it when it comes out of the
+/// queue the second time (lazy deletion), like [`astar`] does.
+#[derive(Clone, Debug)]
+pub struct PriorityQueue<T, P> {
+    heap: BinaryHeap<Queued<T, P>>,
+    pushed: u64,
+}
+
+impl<T, P: Ord> PriorityQueue<T, P> {
+    pub fn new() -> PriorityQueue<T, P> {
+        PriorityQueue { heap: BinaryHeap::new(), pushed: 0 }
+    }
+
+    pub fn with_capacity(capacity: usize) -> PriorityQueue<T, P> {
+        PriorityQueue { heap: BinaryHeap::with_capacity(capacity), pushed: 0 }
+    }
+
+    pub fn push(&mut self, item: T, priority: P) {
+        self.heap.push(Queued { item, priority, order: self.pushed });
+        self.pushed += 1;
+    }
+
+    /// Take out the item with the lowest priority, along with its priority.
+    pub fn pop(&mut self) -> Option<(T, P)> {
+        self.heap.pop().map(|entry| (entry.item, entry.priority))
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+impl<T, P: Ord> Default for PriorityQueue<T, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Item in a [`PriorityQueue`], ordered so the max-heap of the standard
+/// library hands out the lowest priority first.
+#[derive(Clone, Debug)]
+struct Queued<T, P> {
+    item: T,
+    priority: P,
+    order: u64,
+}
+
+impl<T, P: Ord> Ord for Queued<T, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority).then(other.order.cmp(&self.order))
+    }
+}
+
+impl<T, P: Ord> PartialOrd for Queued<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, P: Ord> PartialEq for Queued<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T, P: Ord> Eq for Queued<T, P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coord2, Grid};
+
+    /// Weighted graph where the direct edge from `a` to `d` is not the
+    /// cheapest way there.
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 4), ('d', 10)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn weighted_paths() {
+        let paths = dijkstra(['a'], edges, |_| false);
+        assert_eq!(paths.cost(&'d'), Some(3));
+        assert_eq!(paths.path(&'d'), Some(vec!['a', 'b', 'c', 'd']));
+        assert_eq!(paths.path(&'a'), Some(vec!['a']));
+        assert_eq!(paths.goal(), None);
+        assert_eq!(paths.costs().count(), 4);
+
+        let paths = dijkstra(['a'], edges, |&n| n == 'c');
+        assert_eq!((paths.goal(), paths.goal_cost()), (Some(&'c'), Some(2)));
+
+        let paths = bfs(['a'], |n| edges(n).into_iter().map(|(n, _)| n), |&n| n == 'd');
+        assert_eq!(paths.goal_cost(), Some(1));
+        assert_eq!(paths.path(&'z'), None);
+    }
+
+    #[test]
+    fn searches_agree_on_grids() {
+        let maze = Grid::parse("S..#....\n.#.#.##.\n.#...#..\n.####.#.\n......#E\n", |c| Some(c != '#'), "").unwrap();
+        let (start, end) = (Coord2::new(0, 0), Coord2::new(7, 4));
+        let open = |pos: &Coord2| maze.neighbours(*pos).filter(|&n| maze[n]).collect::<Vec<Coord2>>();
+
+        let by_bfs = bfs([start], open, |&pos| pos == end);
+        let by_dijkstra = dijkstra([start], |pos| open(pos).into_iter().map(|n| (n, 1)), |&pos| pos == end);
+        let by_astar = astar([start], |pos| open(pos).into_iter().map(|n| (n, 1)), |pos| pos.manhattan(end), |&pos| pos == end);
+
+        assert_eq!(by_bfs.goal_cost(), Some(15));
+        assert_eq!(by_dijkstra.goal_cost(), Some(15));
+        assert_eq!(by_astar.goal_cost(), Some(15));
+
+        let path = by_astar.path(&end).unwrap();
+        assert_eq!((path.len(), path[0], path[15]), (16, start, end));
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1 && maze[w[1]]));
+        // A* skips the dead ends that lead away from the goal
+        assert!(by_astar.costs().count() <= by_bfs.costs().count());
+
+        // Starting from both corners, the nearest one wins
+        let paths = bfs([start, Coord2::new(7, 0)], open, |&pos| pos == end);
+        assert_eq!(paths.goal_cost(), Some(4));
+        assert_eq!(paths.path(&end).unwrap()[0], Coord2::new(7, 0));
+    }
+
+    #[test]
+    fn queue_order() {
+        let mut queue = PriorityQueue::new();
+        for (item, priority) in [('a', 3), ('b', 1), ('c', 2), ('d', 1)] {
+            queue.push(item, priority);
+        }
+        assert_eq!(queue.len(), 4);
+        let order = std::iter::from_fn(|| queue.pop()).map(|(item, _)| item).collect::<String>();
+        assert_eq!(order, "bdca");
+        assert!(queue.is_empty());
+    }
+}
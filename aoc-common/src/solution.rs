@@ -0,0 +1,104 @@
+use std::any::Any;
+use std::fmt::Display;
+
+use crate::ParseError;
+
+/// One of the two parts of a challenge.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// The number of this part, as used in the challenge descriptions.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// The solution of a single day.
+///
+/// Every day crate implements this trait on a unit struct named after the
+/// day, e.g. `day01::Day01`, which is then registered with the runner.
+pub trait Solution {
+    /// Day of the advent calendar this is the solution for.
+    const DAY: u8;
+
+    /// Title of the challenge.
+    const TITLE: &'static str;
+
+    /// Puzzle input compiled into the crate, if any.
+    const INPUT: Option<&'static str>;
+
+    /// Parsed representation of the puzzle input, shared by both parts.
+    type Parsed;
+
+    /// Parse the puzzle input, failing with the position of the first problem
+    /// on malformed input.
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(input: &Self::Parsed) -> impl Display;
+
+    fn part2(input: &Self::Parsed) -> impl Display;
+}
+
+/// Object safe version of [`Solution`], so solutions of different days can be
+/// stored together. Implemented for every [`Solution`].
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    fn input(&self) -> Option<&'static str>;
+
+    /// Parse `input`, to be passed on to [`DynSolution::solve`].
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Solve `part` for input returned by [`DynSolution::parse_input`].
+    ///
+    /// # Panics
+    /// Panics if `parsed` was not parsed by this same solution.
+    fn solve(&self, part: Part, parsed: &dyn Any) -> String;
+}
+
+impl<S> DynSolution for S
+where S: Solution + Sync, S::Parsed: 'static {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn input(&self) -> Option<&'static str> {
+        S::INPUT
+    }
+
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        S::parse(input).map(|parsed| Box::new(parsed) as Box<dyn Any>)
+    }
+
+    fn solve(&self, part: Part, parsed: &dyn Any) -> String {
+        let parsed = parsed.downcast_ref::<S::Parsed>()
+            .unwrap_or_else(|| panic!("Input was not parsed by day {}!", S::DAY));
+
+        match part {
+            Part::One => S::part1(parsed).to_string(),
+            Part::Two => S::part2(parsed).to_string(),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Sets of unit cubes on an integer grid of any dimension, for shapes that are
+//! given as a list of the cubes they consist of.
+
+use std::collections::HashSet;
+
+use crate::{search, Bounds, Coord};
+
+/// Set of voxels, the unit cubes at integer coordinates of an `N`-dimensional
+/// grid. Two voxels touch when they are one step apart along a single axis.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VoxelSet<const N: usize> {
+    voxels: HashSet<Coord<N>>,
+}
+
+impl<const N: usize> VoxelSet<N> {
+    pub fn new() -> VoxelSet<N> {
+        VoxelSet { voxels: HashSet::new() }
+    }
+
+    /// Add the voxel at `voxel`, returning whether it was not in the set yet.
+    pub fn insert(&mut self, voxel: Coord<N>) -> bool {
+        self.voxels.insert(voxel)
+    }
+
+    /// Remove the voxel at `voxel`, returning whether it was in the set.
+    pub fn remove(&mut self, voxel: Coord<N>) -> bool {
+        self.voxels.remove(&voxel)
+    }
+
+    pub fn contains(&self, voxel: Coord<N>) -> bool {
+        self.voxels.contains(&voxel)
+    }
+
+    /// Number of voxels.
+    pub fn len(&self) -> usize {
+        self.voxels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.voxels.is_empty()
+    }
+
+    /// All voxels, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = Coord<N>> + '_ {
+        self.voxels.iter().copied()
+    }
+
+    /// Bounding box of the voxels, or `None` if there are none.
+    pub fn bounds(&self) -> Option<Bounds<N>> {
+        Bounds::of(self.iter())
+    }
+
+    /// Number of faces of voxels that do not touch another voxel of the set,
+    /// including the faces facing holes inside the shape.
+    pub fn surface_area(&self) -> usize {
+        self.iter()
+        .flat_map(|voxel| voxel.orthogonal_neighbours())
+        .filter(|&neighbour| !self.contains(neighbour))
+        .count()
+    }
+
+    /// The empty voxels outside the shape that can be reached from the edge of
+    /// its bounding box grown by one, without passing through the shape. This
+    /// leaves out the holes that are closed off inside it.
+    pub fn exterior(&self) -> VoxelSet<N> {
+        let Some(bounds) = self.bounds().map(|bounds| bounds.grow(1)) else {
+            return VoxelSet::new();
+        };
+
+        let open = |voxel: &Coord<N>| {
+            voxel.orthogonal_neighbours()
+            .filter(|&n| bounds.contains(n) && !self.contains(n))
+            .collect::<Vec<Coord<N>>>()
+        };
+        let paths = search::bfs([bounds.min], open, |_| false);
+        paths.costs().map(|(&voxel, _)| voxel).collect()
+    }
+
+    /// Number of faces of voxels that touch the outside of the shape, so
+    /// [`surface_area`](Self::surface_area) without the faces of holes inside
+    /// it.
+    pub fn exterior_surface_area(&self) -> usize {
+        let exterior = self.exterior();
+        self.iter()
+        .flat_map(|voxel| voxel.orthogonal_neighbours())
+        .filter(|&neighbour| exterior.contains(neighbour))
+        .count()
+    }
+}
+
+impl<const N: usize> FromIterator<Coord<N>> for VoxelSet<N> {
+    fn from_iter<I: IntoIterator<Item = Coord<N>>>(iter: I) -> VoxelSet<N> {
+        VoxelSet { voxels: iter.into_iter().collect() }
+    }
+}
+
+impl<const N: usize> Extend<Coord<N>> for VoxelSet<N> {
+    fn extend<I: IntoIterator<Item = Coord<N>>>(&mut self, iter: I) {
+        self.voxels.extend(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coord2, Coord3};
+
+    #[test]
+    fn surface_areas() {
+        let pair = [Coord3::new(1, 1, 1), Coord3::new(2, 1, 1)].into_iter().collect::<VoxelSet<3>>();
+        assert_eq!(pair.surface_area(), 10);
+        assert_eq!(pair.exterior_surface_area(), 10);
+
+        // A 3x3x3 cube with the middle left out has a hole of 6 faces
+        let mut hollow = Bounds { min: Coord3::splat(0), max: Coord3::splat(2) }.points().collect::<VoxelSet<3>>();
+        assert!(hollow.remove(Coord3::splat(1)));
+        assert_eq!((hollow.len(), hollow.surface_area()), (26, 60));
+        assert_eq!(hollow.exterior_surface_area(), 54);
+        assert!(!hollow.exterior().contains(Coord3::splat(1)));
+        assert_eq!(hollow.exterior().len(), 5 * 5 * 5 - 27);
+
+        assert_eq!(VoxelSet::<3>::new().exterior_surface_area(), 0);
+    }
+
+    #[test]
+    fn other_dimensions() {
+        // A ring of squares in 2D encloses the square in its middle
+        let mut ring = Bounds { min: Coord2::splat(0), max: Coord2::splat(2) }.points().collect::<VoxelSet<2>>();
+        ring.remove(Coord2::new(1, 1));
+        assert_eq!(ring.surface_area(), 16);
+        assert_eq!(ring.exterior_surface_area(), 12);
+        assert_eq!(ring.bounds().map(|b| b.size()), Some(Coord2::splat(3)));
+    }
+}
@@ -1,74 +1,131 @@
-#![allow(dead_code)]
-use std::fmt::Display;
-
-use criterion::{criterion_group, criterion_main, Criterion};
-
-use day01;
-use day02;
-use day03;
-use day04;
-use day05;
-use day06;
-use day07;
-use day08;
-use day09;
-use day10;
-use day11;
-use day12;
-use day13;
-use day14;
-use day15;
-// use day16;
-// use day17;
-// use day18;
-// use day19;
-use day20;
-use day21;
-// use day22;
-// use day23;
-// use day24;
-// use day25;
-
-#[path = "../src/main.rs"]
-mod main;
-
-fn bench_parts<F1, F2, O1, O2>(c: &mut Criterion, title: &str, part1: F1, part2: F2)
-where F1: Fn() -> O1, F2: Fn() -> O2, O1 : Display, O2: Display {
-    c.bench_function(format!("{} - Part 1", title).as_str(), |b| {
-        b.iter(|| part1())
-    });
+//! Benchmarks of every registered day, timing parsing and both parts on the
+//! parsed input separately.
+//!
+//! Afterwards the median times are compared against the baseline in
+//! `bench-baseline.json`, flagging benchmarks that got slower by more than 10%.
+//! The comparison is configured with environment variables, as all arguments
+//! go to Criterion:
+//! - `AOC_BENCH_BASELINE`: path of the baseline
+//! - `AOC_BENCH_THRESHOLD`: allowed slowdown in percent
+//! - `AOC_BENCH_SAVE`: when set, save the results as the new baseline
 
-    c.bench_function(format!("{} - Part 2", title).as_str(), |b| {
-        b.iter(|| part2())
-    });
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::SystemTime;
+
+use aoc2022::baseline::{self, Baseline};
+use aoc2022::registry::DAYS;
+use aoc_common::Part;
+use criterion::{black_box, Criterion};
+use serde::Deserialize;
+
+fn bench_days(c: &mut Criterion) -> Vec<String> {
+    let mut ids = Vec::new();
+    for day in DAYS {
+        // Without an embedded input there is nothing to benchmark
+        let Some(input) = day.input() else {
+            continue;
+        };
+
+        let name = format!("day{:02}", day.day());
+        let mut group = c.benchmark_group(&name);
+        group.bench_function("parse", |b| b.iter(|| day.parse_input(black_box(input))));
+        ids.push(format!("{}/parse", name));
+
+        let parsed = day.parse_input(input)
+            .unwrap_or_else(|e| panic!("Invalid input for day {}: {}", day.day(), e));
+        for part in Part::ALL {
+            group.bench_function(format!("part{}", part), |b| b.iter(|| day.solve(part, black_box(parsed.as_ref()))));
+            ids.push(format!("{}/part{}", name, part));
+        }
+        group.finish();
+    }
+
+    ids
+}
+
+/// The part of Criterion's `estimates.json` we need.
+#[derive(Deserialize)]
+struct Estimates {
+    median: Estimate,
 }
 
-fn bench_main(c: &mut Criterion) {
-    bench_parts(c, "Day 01", day01::part1, day01::part2);
-    bench_parts(c, "Day 02", day02::part1, day02::part2);
-    bench_parts(c, "Day 03", day03::part1, day03::part2);
-    bench_parts(c, "Day 04", day04::part1, day04::part2);
-    bench_parts(c, "Day 05", day05::part1, day05::part2);
-    bench_parts(c, "Day 06", day06::part1, day06::part2);
-    bench_parts(c, "Day 07", day07::part1, day07::part2);
-    bench_parts(c, "Day 08", day08::part1, day08::part2);
-    bench_parts(c, "Day 09", day09::part1, day09::part2);
-    bench_parts(c, "Day 10", day10::part1, day10::part2);
-    bench_parts(c, "Day 11", day11::part1, day11::part2);
-    bench_parts(c, "Day 12", day12::part1, day12::part2);
-    bench_parts(c, "Day 13", day13::part1, day13::part2);
-    bench_parts(c, "Day 14", day14::part1, day14::part2);
-    bench_parts(c, "Day 15", day15::part1, day15::part2);
-    // bench_parts(c, "Day 16", day16::part1, day16::part2);
-    // bench_parts(c, "Day 17", day17::part1, day17::part2);
-    // bench_parts(c, "Day 18", day18::part1, day18::part2);
-    // bench_parts(c, "Day 19", day19::part1, day19::part2);
-    bench_parts(c, "Day 20", day20::part1, day20::part2);
-    bench_parts(c, "Day 21", day21::part1, day21::part2);
-    // bench_parts(c, "Day 22", day22::part1, day22::part2);
-    // bench_parts(c, "Day 23", day23::part1, day23::part2);
-    // bench_parts(c, "Day 24", day24::part1, day24::part2);
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
 }
 
-criterion_group!(benches, bench_main);
-criterion_main!(benches);
+/// Median times of the benchmarks that ran since `start`, read from Criterion's
+/// output. Benchmarks that were filtered out keep their old results on disk,
+/// which are skipped.
+fn results(output: &Path, ids: &[String], start: SystemTime) -> Baseline {
+    let benchmarks = ids.iter()
+        .filter_map(|id| {
+            let path = output.join(id).join("new").join("estimates.json");
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            if modified < start {
+                return None;
+            }
+
+            let estimates: Estimates = serde_json::from_str(&std::fs::read_to_string(&path).ok()?).ok()?;
+            Some((id.clone(), estimates.median.point_estimate))
+        })
+        .collect();
+
+    Baseline { benchmarks }
+}
+
+fn main() -> ExitCode {
+    let start = SystemTime::now();
+
+    // Same location as Criterion uses by default, but set explicitly so the
+    // results can be read back
+    let output = std::env::var_os("CRITERION_HOME").map(PathBuf::from)
+        .or_else(|| std::env::var_os("CARGO_TARGET_DIR").map(|dir| PathBuf::from(dir).join("criterion")))
+        .unwrap_or_else(|| PathBuf::from("target/criterion"));
+
+    let mut c = Criterion::default().output_directory(&output).configure_from_args();
+    let ids = bench_days(&mut c);
+    c.final_summary();
+
+    let current = results(&output, &ids, start);
+    if current.benchmarks.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+
+    let path = PathBuf::from(std::env::var_os("AOC_BENCH_BASELINE").unwrap_or(baseline::DEFAULT_PATH.into()));
+    let threshold = match std::env::var("AOC_BENCH_THRESHOLD") {
+        Ok(percent) => match percent.parse::<f64>() {
+            Ok(percent) => percent / 100.0,
+            Err(_) => {
+                eprintln!("error: invalid AOC_BENCH_THRESHOLD `{}`, expected a percentage", percent);
+                return ExitCode::FAILURE;
+            },
+        },
+        Err(_) => baseline::DEFAULT_THRESHOLD,
+    };
+
+    let result = Baseline::load(&path).and_then(|base| {
+        let report = base.compare(&current, threshold);
+        println!("\nCompared to {}:\n{}", path.display(), report);
+
+        if std::env::var_os("AOC_BENCH_SAVE").is_some() {
+            // Keep the results of benchmarks that did not run this time
+            let mut saved = base;
+            saved.benchmarks.extend(current.benchmarks);
+            saved.save(&path)?;
+            println!("Saved results as the new baseline in {}", path.display());
+        }
+
+        Ok(report.regressions().count() == 0)
+    });
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        },
+    }
+}
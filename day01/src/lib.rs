@@ -1,15 +1,80 @@
+// `main.rs` doubles as the binary of this day, which prints the answers of
+// both parts, and as the module holding the challenge logic.
+#![allow(special_module_name)]
+
 use std::fmt::Display;
 
+use aoc_common::{ParseError, Solution};
+
 mod main;
 
-pub fn part1() -> impl Display {
-    let raw_input = include_str!("../input").to_owned();
-    let parsed_input = main::parse_input(raw_input);
-    main::part1(&parsed_input)
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled.
+#[cfg(feature = "embedded-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input"));
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled.
+#[cfg(not(feature = "embedded-input"))]
+pub const INPUT: Option<&str> = None;
+
+/// Day 1: Calorie Counting
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+    const INPUT: Option<&'static str> = INPUT;
+
+    type Parsed = main::ParsedInput;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        main::parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> impl Display {
+        main::part1(input)
+    }
+
+    fn part2(input: &Self::Parsed) -> impl Display {
+        main::part2(input)
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<String, ParseError> {
+    Ok(Day01::part1(&Day01::parse(input)?).to_string())
+}
+
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    Ok(Day01::part2(&Day01::parse(input)?).to_string())
 }
 
-pub fn part2() -> impl Display {
-    let raw_input = include_str!("../input").to_owned();
-    let parsed_input = main::parse_input(raw_input);
-    main::part2(&parsed_input)
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::{main, Day01};
+
+    const SAMPLE: &str = include_str!("../sample");
+    const INPUT: &str = include_str!("../input");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(main::part1(&main::parse_input(SAMPLE).unwrap()).to_string(), "24000");
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(Day01::part1(&Day01::parse(INPUT).unwrap()).to_string(), "70698");
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(main::part2(&main::parse_input(SAMPLE).unwrap()).to_string(), "45000");
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(Day01::part2(&Day01::parse(INPUT).unwrap()).to_string(), "206643");
+    }
+}
@@ -1,10 +1,12 @@
 use std::fmt::Display;
 
+use aoc_common::{parse, ParseError};
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
-type ParsedInput = Vec<Vec<i32>>;
+pub type ParsedInput = Vec<Vec<i32>>;
 
-pub fn parse_input(input: String) -> ParsedInput {
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
     // Create a new vector to hold the parsed result
     let mut parsed: ParsedInput = Vec::new();
 
@@ -13,18 +15,19 @@ pub fn parse_input(input: String) -> ParsedInput {
 
     // Go through all lines in the input
     for l in input.lines() {
-        // If we parsed a number, add it to the last vector in the result vecvec
-        if let Ok(i) = l.parse::<i32>() {
-            parsed.last_mut().unwrap().push(i);
-        } else {
-        // If we could not parse a number, it means it is an empty line, so add
-        // a new vector for the new elf.
+        if l.is_empty() {
+            // An empty line separates the elves, so add a new vector for the
+            // new elf.
             parsed.push(Vec::new());
+        } else {
+            // Otherwise the line holds a number, add it to the last vector in
+            // the result vecvec
+            parsed.last_mut().unwrap().push(parse::from_str(input, l, "a number of calories")?);
         }
     }
 
     // Return the parsed input vector.
-    parsed
+    Ok(parsed)
 }
 
 pub fn part1(input: &ParsedInput) -> impl Display {
@@ -69,36 +72,17 @@ pub fn part2(input: &ParsedInput) -> impl Display {
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(read_file("sample"));
-    let input = parse_input(read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "24000";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "45000";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
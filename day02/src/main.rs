@@ -1,40 +1,45 @@
 use std::fmt::Display;
 
+use aoc_common::ParseError;
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
-type ParsedInput = Vec<(char, char)>;
+pub type ParsedInput = Vec<(char, char)>;
 
-pub fn parse_input(input: &str) -> ParsedInput {
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
     input.lines()
         .map(|l| {
             // Take first and third characters from line as moves
-            let theirs = l.chars().next().expect("Expected opponent move!"); 
-            let ours = l.chars().skip(2).next().expect("Expected opponent move!");
-            (theirs, ours)
+            match l.as_bytes() {
+                [theirs @ b'A'..=b'C', b' ', ours @ b'X'..=b'Z'] => Ok((*theirs as char, *ours as char)),
+                [b'A'..=b'C', b' ', ..] => Err(ParseError::at(input, &l[2..], "our move (`X`, `Y` or `Z`)")),
+                [b'A'..=b'C', ..] => Err(ParseError::at(input, &l[1..], "` `")),
+                _ => Err(ParseError::at(input, l, "opponent move (`A`, `B` or `C`)")),
+            }
         }
-        ).collect::<Vec<(char, char)>>()
+        ).collect::<Result<Vec<(char, char)>, ParseError>>()
 }
 
 pub fn part1(input: &ParsedInput) -> impl Display {
     // Compute lookup table (LUT) for all 9 different scenarios based on their
     // on their move and our move
-    let mut LUT: [i16; 6031] = [0;6031];
-    LUT[('A' as usize) * ('X' as usize)] = 1 + 3; // rock + draw;
-    LUT[('A' as usize) * ('Y' as usize)] = 2 + 6; // paper + win;
-    LUT[('A' as usize) * ('Z' as usize)] = 3 + 0; // scissors + loss;
-    LUT[('B' as usize) * ('X' as usize)] = 1 + 0; // rock + loss;
-    LUT[('B' as usize) * ('Y' as usize)] = 2 + 3; // paper + draw;
-    LUT[('B' as usize) * ('Z' as usize)] = 3 + 6; // scissors + win;
-    LUT[('C' as usize) * ('X' as usize)] = 1 + 6; // rock + win;
-    LUT[('C' as usize) * ('Y' as usize)] = 2 + 0; // paper + loss;
-    LUT[('C' as usize) * ('Z' as usize)] = 3 + 3; // scissors + draw;
+    let mut lut: [i16; 6031] = [0;6031];
+    lut[('A' as usize) * ('X' as usize)] = 1 + 3; // rock + draw;
+    lut[('A' as usize) * ('Y' as usize)] = 2 + 6; // paper + win;
+    lut[('A' as usize) * ('Z' as usize)] = 3; // scissors + loss;
+    lut[('B' as usize) * ('X' as usize)] = 1; // rock + loss;
+    lut[('B' as usize) * ('Y' as usize)] = 2 + 3; // paper + draw;
+    lut[('B' as usize) * ('Z' as usize)] = 3 + 6; // scissors + win;
+    lut[('C' as usize) * ('X' as usize)] = 1 + 6; // rock + win;
+    lut[('C' as usize) * ('Y' as usize)] = 2; // paper + loss;
+    lut[('C' as usize) * ('Z' as usize)] = 3 + 3; // scissors + draw;
 
     // Loop through input and compute score
     input.iter()
-        // Compute index in LUT
+        // Compute index in lut
         .map(|&(theirs, ours)| (theirs as usize) * (ours as usize))
-        // Get value from LUT
-        .map(|i| LUT[i])
+        // Get value from lut
+        .map(|i| lut[i])
         // Compute sum
         .sum::<i16>()
 }
@@ -42,23 +47,23 @@ pub fn part1(input: &ParsedInput) -> impl Display {
 pub fn part2(input: &ParsedInput) -> impl Display {
     // Compute lookup table (LUT) for all 9 different scenarios based on their
     // on their move and the desired outcome.
-    let mut LUT: [i16; 6031] = [0;6031];
-    LUT[('A' as usize) * ('X' as usize)] = 3 + 0; // scissors + loss
-    LUT[('A' as usize) * ('Y' as usize)] = 1 + 3; // rock + draw
-    LUT[('A' as usize) * ('Z' as usize)] = 2 + 6; // paper + win
-    LUT[('B' as usize) * ('X' as usize)] = 1 + 0; // rock + loss
-    LUT[('B' as usize) * ('Y' as usize)] = 2 + 3; // paper + draw
-    LUT[('B' as usize) * ('Z' as usize)] = 3 + 6; // scissors + win
-    LUT[('C' as usize) * ('X' as usize)] = 2 + 0; // paper + loss
-    LUT[('C' as usize) * ('Y' as usize)] = 3 + 3; // scissors + draw
-    LUT[('C' as usize) * ('Z' as usize)] = 1 + 6; // rock + win
+    let mut lut: [i16; 6031] = [0;6031];
+    lut[('A' as usize) * ('X' as usize)] = 3; // scissors + loss
+    lut[('A' as usize) * ('Y' as usize)] = 1 + 3; // rock + draw
+    lut[('A' as usize) * ('Z' as usize)] = 2 + 6; // paper + win
+    lut[('B' as usize) * ('X' as usize)] = 1; // rock + loss
+    lut[('B' as usize) * ('Y' as usize)] = 2 + 3; // paper + draw
+    lut[('B' as usize) * ('Z' as usize)] = 3 + 6; // scissors + win
+    lut[('C' as usize) * ('X' as usize)] = 2; // paper + loss
+    lut[('C' as usize) * ('Y' as usize)] = 3 + 3; // scissors + draw
+    lut[('C' as usize) * ('Z' as usize)] = 1 + 6; // rock + win
 
     // Loop through input and compute score
     input.iter()
-        // Compute index in LUT
+        // Compute index in lut
         .map(|&(theirs, ours)| (theirs as usize) * (ours as usize))
-        // Get value from LUT
-        .map(|i| LUT[i])
+        // Get value from lut
+        .map(|i| lut[i])
         // Compute sum
         .sum::<i16>()
 }
@@ -66,36 +71,17 @@ pub fn part2(input: &ParsedInput) -> impl Display {
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-    let input = parse_input(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "15";
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "12";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
@@ -1,17 +1,26 @@
 use std::fmt::Display;
 
+use aoc_common::ParseError;
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
-type ParsedInput = Vec<String>;
-
-pub fn parse_input(input: &str) -> ParsedInput {
-    input.lines().map(|l|l.into()).collect()
+pub type ParsedInput = Vec<String>;
+
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    input.lines().map(|l| {
+        // Items are identified by letters, and the compartments are of equal size
+        match l.find(|c: char| !c.is_ascii_alphabetic()) {
+            Some(i) => Err(ParseError::at(input, &l[i..], "an item (`a`-`z` or `A`-`Z`)")),
+            None if l.len() % 2 != 0 => Err(ParseError::at(input, l, "an even number of items")),
+            None => Ok(l.into()),
+        }
+    }).collect()
 }
 
 
 pub fn char_to_usize(input: char) -> usize {
     // Convert a char to an usize (for shifting) using the mapping specified in the challenge
-    if ('a'..='z').contains(&input) {
+    if input.is_ascii_lowercase() {
         input as usize - 'a' as usize
     } else {
         (input as usize - 'A' as usize) + 26
@@ -60,36 +69,17 @@ pub fn part2(input: &ParsedInput) -> impl Display {
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-    let input = parse_input(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "157";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "70";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
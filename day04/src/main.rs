@@ -1,77 +1,55 @@
 use std::fmt::Display;
+use std::ops::Range;
+
+use aoc_common::{parse, IntervalSet, ParseError};
 
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
-type ParsedInput = Vec<((usize, usize), (usize, usize))>;
-
-pub fn parse_input(input: &str) -> ParsedInput {
+pub type ParsedInput = Vec<(Range<i64>, Range<i64>)>;
+
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    let section = |s| parse::from_str::<i64>(input, s, "a section number");
+    // Section assignments include both ends
+    let sections = |s| {
+        let (first, last) = parse::split_once(input, s, "-")?;
+        Ok(section(first)?..section(last)? + 1)
+    };
     input.lines()
-        .map(|l| l.split_once(",").expect("Expected delim"))
-        .map(|(left, right)| (left.split_once("-").expect("expected dash"), right.split_once("-").expect("expected dash")))
-        .map(|((ll, lr), (rl, rr))| {
-            (
-                (ll.parse().expect("first coordinate"), lr.parse().expect("second coordinate")),
-                (rl.parse().expect("third coordinate"), rr.parse().expect("fourth coordinate")),
-            )
+        .map(|l| {
+            let (left, right) = parse::split_once(input, l, ",")?;
+            Ok((sections(left)?, sections(right)?))
         }).collect()
 }
 
 pub fn part1(input: &ParsedInput) -> impl Display {
     input.iter()
-    .filter(|((ll, lr), (rl,rr))| {
-           (ll <= rl && lr >= rr) // right interval is contained in left interval
-        || (rl <= ll && rr >= lr) // left interval is contained in right interval
+    .filter(|(left, right)| {
+           IntervalSet::from(left.clone()).contains_range(right) // right interval is contained in left interval
+        || IntervalSet::from(right.clone()).contains_range(left) // left interval is contained in right interval
     })
     .count()
 }
 
 pub fn part2(input: &ParsedInput) -> impl Display {
     input.iter()
-    // .filter(|((ll, lr), (rl,rr))| {
-    //        (ll <= rl && rl <= lr) // left edge of right interval is contained in left interval
-    //     || (ll <= rr && rr <= lr) // right edge of right interval is contained in left interval
-    //     || (rl <= ll && ll <= rr) // left edge of left interval is contained in right interval
-    //     || (rl <= lr && lr <= rr) // right edge of left interval is contained in right interval
-    // })
-    .filter(|((ll, lr), (rl,rr))| {
-        lr >= rl && ll <= rr
-    })
+    .filter(|(left, right)| IntervalSet::from(left.clone()).overlaps(right))
     .count()
 }
 
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-    let input = parse_input(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "2";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "4";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
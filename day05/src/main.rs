@@ -1,17 +1,20 @@
 use std::fmt::Display;
 
+use aoc_common::{parse, ParseError};
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
 type Crates = Vec<Vec<char>>;
 type Moves = Vec<(usize, usize, usize)>;
-type ParsedInput = (Crates, Moves);
+pub type ParsedInput = (Crates, Moves);
 
-pub fn parse_input(input: &str) -> ParsedInput {
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
     // Split input in initial crate config and move list
-    let (crates, moves) = input.split_once("\n\n").expect("Invalid input structure");
+    let (crates, moves) = input.split_once("\n\n")
+        .ok_or_else(|| ParseError::at_end(input, "an empty line between the crates and the moves"))?;
     
     // Compute the number of stacks in the input
-    let num_stacks = (crates.find('\n').unwrap() + 1) / 4;
+    let num_stacks = (crates.find('\n').unwrap_or(crates.len()) + 1) / 4;
     
     // Create a data structure to hold the values from the input
     let mut stacks: Crates = vec![Vec::with_capacity(50); num_stacks];
@@ -32,16 +35,22 @@ pub fn parse_input(input: &str) -> ParsedInput {
         )
     );
 
+    // Parse a stack number of a move, which start at 1 in the input
+    let stack = |s| match parse::from_str::<usize>(input, s, "a stack number") {
+        Ok(i) if (1..=num_stacks).contains(&i) => Ok(i - 1),
+        _ => Err(ParseError::at(input, s, format!("a stack number from 1 to {}", num_stacks))),
+    };
+
     let parsed_moves = moves.lines()
-    .map(|l| l.split(" "))
-    .map(|mut split| (
-        split.nth(1).expect("amnt").parse::<usize>().unwrap(),
-        split.nth(1).expect("from").parse::<usize>().unwrap() - 1,
-        split.nth(1).expect("to").parse::<usize>().unwrap() - 1,
-    ))
-    .collect::<Moves>();
+    .map(|l| {
+        let rest = l.strip_prefix("move ").ok_or_else(|| ParseError::at(input, l, "`move`"))?;
+        let (amount, rest) = parse::split_once(input, rest, " from ")?;
+        let (from, to) = parse::split_once(input, rest, " to ")?;
+        Ok((parse::from_str(input, amount, "a number of crates")?, stack(from)?, stack(to)?))
+    })
+    .collect::<Result<Moves, ParseError>>()?;
 
-    (stacks, parsed_moves)
+    Ok((stacks, parsed_moves))
 }
 
 pub fn part1(input: &ParsedInput) -> impl Display {
@@ -87,36 +96,17 @@ pub fn part2(input: &ParsedInput) -> impl Display {
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-    let input = parse_input(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "CMZ";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "MCD";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
@@ -1,8 +1,10 @@
 use std::fmt::Display;
 
+use aoc_common::ParseError;
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
-type ParsedInput = Vec<u32>;
+pub type ParsedInput = Vec<u32>;
 
 #[inline(always)]
 pub fn char_to_shift(c: &u8) -> usize {
@@ -10,15 +12,21 @@ pub fn char_to_shift(c: &u8) -> usize {
     (*c - b'a') as usize
 }
 
-pub fn parse_input(input: &str) -> ParsedInput {
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    // The datastream may only contain lowercase letters
+    let stream = input.trim_end();
+    if let Some(i) = stream.find(|c: char| !c.is_ascii_lowercase()) {
+        return Err(ParseError::at_offset(input, i, "a lowercase letter"));
+    }
+
     // Take input as byte, iterate over them
-    input.as_bytes().iter()
+    Ok(stream.as_bytes().iter()
     // Map each character to a bitshift
     .map(char_to_shift)
     // Leftshift 0x00000001 by the calculated bitshift
     .map(|shift| 1_u32 << shift)
     // Collect the shifted values
-    .collect()
+    .collect())
 }
 
 pub fn part1(input: &ParsedInput) -> impl Display {
@@ -62,36 +70,17 @@ pub fn part2(input: &ParsedInput) -> impl Display {
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-    let input = parse_input(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "11";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "26";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
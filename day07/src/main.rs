@@ -1,6 +1,8 @@
 use std::fmt::Display;
 use std::collections::HashMap;
 
+use aoc_common::{parse, ParseError};
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
 
@@ -11,9 +13,9 @@ pub enum DirEntry {
 }
 
 type Path = String;
-type ParsedInput = HashMap<Path, Vec<DirEntry>>;
+pub type ParsedInput = HashMap<Path, Vec<DirEntry>>;
 
-pub fn parse_input(input: &str) -> ParsedInput {
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
     // Create result directory
     let mut result: ParsedInput = HashMap::with_capacity(256);
     let mut path = String::from("");
@@ -24,11 +26,17 @@ pub fn parse_input(input: &str) -> ParsedInput {
             // In case of `cd /`, change path to ""
             Some(("$", "cd /")) => path = String::from(""),
             // In case of `cd ..`, split path on right-most '/', and set path to left side of split
-            Some(("$", "cd ..")) => path = format!("{}", path.rsplit_once("/").unwrap().0),
+            Some(("$", "cd ..")) => match path.rsplit_once("/") {
+                Some((parent, _)) => path = parent.to_string(),
+                None => return Err(ParseError::at(input, &l[5..], "a directory to change into, already at `/`")),
+            },
             // In case of `ls`, do nothing
             Some(("$", "ls")) => (), // ls command, do nothing for now
             // In case of `cd [dirname]`, set path to current_path/[dirname]
-            Some(("$", cd_dirname)) => path = format!("{}/{}", path, &cd_dirname[3..]),
+            Some(("$", cmd)) => match cmd.strip_prefix("cd ") {
+                Some(dirname) => path = format!("{}/{}", path, dirname),
+                None => return Err(ParseError::at(input, cmd, "a command (`cd` or `ls`)")),
+            },
             // In case we see a dir entry (after a `$ ls`), add a directory entry
             // to the result hashmap with the path of the directory
             Some(("dir", dirname)) => {
@@ -41,21 +49,21 @@ pub fn parse_input(input: &str) -> ParsedInput {
             Some((size, filename)) => {
                 result.entry(path.clone())
                 .or_default()
-                .push(DirEntry::File(format!("{}/{}", path, filename), size.parse::<u64>().unwrap()));
+                .push(DirEntry::File(format!("{}/{}", path, filename), parse::from_str(input, size, "a file size or `dir`")?));
             },
-            // In case our split_once could not split do nothing
-            None => println!("Nothing here"),
+            // Every line of the terminal output contains a space
+            None => return Err(ParseError::at(input, l, "a command or directory entry")),
         };
     }
     // println!("{}", result.len());
     
-    result
+    Ok(result)
 }
 
 pub fn rec_compute_sizes(fs: &ParsedInput, path: &str, cache: &mut HashMap<String, u64>) -> u64 {
     // Compute size of directory `path` in fs.
     let size = fs.get(path).map_or(0, |entries|
-    entries.into_iter().map(|e| 
+    entries.iter().map(|e| 
         match e {
             DirEntry::Directory(d) => rec_compute_sizes(fs, d, cache),
             DirEntry::File(_p, s) => *s,
@@ -85,9 +93,7 @@ pub fn part1(input: &ParsedInput) -> impl Display {
     let cache = compute_sizes(input);
 
     // Loop over directories & sizes
-    cache.into_iter()
-    // Forget about directory path
-    .map(|(_k, v)| v)
+    cache.into_values()
     // filter out directories larger than 100.000
     .filter(|&s| s <= 100_000)
     // sum up sizes
@@ -106,9 +112,7 @@ pub fn part2(input: &ParsedInput) -> impl Display {
     let to_free = space_needed - space_available;
 
     // Loop over directories & sizes
-    cache.into_iter()
-    // Forget about directory path
-    .map(|(_k, v)| v)
+    cache.into_values()
     // filter out directories that don't free up enough space
     .filter(|&s| s >= to_free)
     // Get the smallest directory size that frees up enough space
@@ -118,37 +122,17 @@ pub fn part2(input: &ParsedInput) -> impl Display {
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "95437";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    let input = parse_input(&read_file("input"));
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "24933642";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use aoc_common::{Grid, ParseError};
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
 const NORTH: usize = 0;
@@ -24,94 +26,85 @@ impl Tree {
     }
 }
 
-type ParsedInput<const N: usize> = [[Tree; N]; N];
+pub type ParsedInput = Grid<Tree>;
 
-pub fn parse_input<const N: usize>(input: &str) -> ParsedInput<N> {
-    input.lines()
-    .map(|l| 
-        l.as_bytes().into_iter()
-        .map(|&c| Tree::from_u8(c - b'0'))
-        .collect::<Vec<Tree>>().try_into().expect("Expected [Tree; N]")
-    )
-    .collect::<Vec<[Tree; N]>>().try_into().expect("Expected [[Tree; N]; N]")
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|h| Tree::from_u8(h as u8)), "a tree height (`0`-`9`)")
 }
 
 
-pub fn part1<const N: usize>(input: &ParsedInput<N>) -> impl Display {
+pub fn part1(input: &ParsedInput) -> impl Display {
     // Compute which trees are visible from which directions
     let mut field = input.clone();
-    let h = input.len();
-    let w = input[0].len();
+    let h = input.height();
+    let w = input.width();
 
     // north to south
     // First row
     for i in 0..w {
-        field[0][i].visible[NORTH] = true;
+        field[(i, 0)].visible[NORTH] = true;
     }
 
     for j in 1..h { 
         for i in 0..w {
-            field[j][i].visible[NORTH] = field[j-1][i].heights[NORTH] < field[j][i].height;
-            field[j][i].heights[NORTH] = field[j-1][i].heights[NORTH].max(field[j][i].height);
+            field[(i, j)].visible[NORTH] = field[(i, j-1)].heights[NORTH] < field[(i, j)].height;
+            field[(i, j)].heights[NORTH] = field[(i, j-1)].heights[NORTH].max(field[(i, j)].height);
         }
     }
 
     // south to north
     // First row
     for i in 0..w {
-        field[h-1][i].visible[SOUTH] = true;
+        field[(i, h-1)].visible[SOUTH] = true;
     }
 
     for j in (0..h-1).rev() { 
         for i in 0..w {
-            field[j][i].visible[SOUTH] = field[j+1][i].heights[SOUTH] < field[j][i].height;
-            field[j][i].heights[SOUTH] = field[j+1][i].heights[SOUTH].max(field[j][i].height);
+            field[(i, j)].visible[SOUTH] = field[(i, j+1)].heights[SOUTH] < field[(i, j)].height;
+            field[(i, j)].heights[SOUTH] = field[(i, j+1)].heights[SOUTH].max(field[(i, j)].height);
         }
     }
 
     // west to east
     // First row
     for j in 0..h {
-        field[j][0].visible[WEST] = true;
+        field[(0, j)].visible[WEST] = true;
     }
 
     for j in 0..h { 
         for i in 1..w {
-            field[j][i].visible[WEST] = field[j][i-1].heights[WEST] < field[j][i].height;
-            field[j][i].heights[WEST] = field[j][i-1].heights[WEST].max(field[j][i].height);
+            field[(i, j)].visible[WEST] = field[(i-1, j)].heights[WEST] < field[(i, j)].height;
+            field[(i, j)].heights[WEST] = field[(i-1, j)].heights[WEST].max(field[(i, j)].height);
         }
     }
 
     // east to west
     // First row
     for j in 0..h {
-        field[j][w-1].visible[EAST] = true;
+        field[(w-1, j)].visible[EAST] = true;
     }
 
     for j in 0..h { 
         for i in (0..w-1).rev() {
-            field[j][i].visible[EAST] = field[j][i+1].heights[EAST] < field[j][i].height;
-            field[j][i].heights[EAST] = field[j][i+1].heights[EAST].max(field[j][i].height);
+            field[(i, j)].visible[EAST] = field[(i+1, j)].heights[EAST] < field[(i, j)].height;
+            field[(i, j)].heights[EAST] = field[(i+1, j)].heights[EAST].max(field[(i, j)].height);
         }
     }
 
     // Count the number of visible trees
-    field.into_iter()
-    .map(|r| 
-        r.into_iter()
-        .filter(|t| 
-            t.visible[NORTH] | t.visible[EAST] | t.visible[SOUTH] | t.visible[WEST]
-        )
-        .count()
-    ).sum::<usize>()
+    field.cells().iter()
+    .filter(|t| 
+        t.visible[NORTH] | t.visible[EAST] | t.visible[SOUTH] | t.visible[WEST]
+    )
+    .count()
 }
 
-pub fn part2<const N: usize>(input: &ParsedInput<N>) -> impl Display {
-    let field = input.clone();
-    let h = input.len();
-    let w = input[0].len();
+pub fn part2(input: &ParsedInput) -> impl Display {
+    let field = input;
+    let h = input.height();
+    let w = input.width();
 
-    let mut scores: [[usize; N]; N] = [[1; N]; N];
+    let mut scores: Grid<usize> = Grid::new(w, h, 1);
 
     for y in 0..h {
         for x in 0..w {
@@ -119,7 +112,7 @@ pub fn part2<const N: usize>(input: &ParsedInput<N>) -> impl Display {
             let mut up = 0;
             for j in (0..y).rev() {
                 up += 1;
-                if field[j][x].height >= field[y][x].height {
+                if field[(x, j)].height >= field[(x, y)].height {
                     break;
                 }
             }
@@ -128,7 +121,7 @@ pub fn part2<const N: usize>(input: &ParsedInput<N>) -> impl Display {
             let mut right = 0;
             for i in x+1..w {
                 right += 1;
-                if field[y][i].height >= field[y][x].height {
+                if field[(i, y)].height >= field[(x, y)].height {
                     break;
                 }
             }
@@ -137,7 +130,7 @@ pub fn part2<const N: usize>(input: &ParsedInput<N>) -> impl Display {
             let mut down = 0;
             for j in y+1..h {
                 down += 1;
-                if field[j][x].height >= field[y][x].height {
+                if field[(x, j)].height >= field[(x, y)].height {
                     break;
                 }
             }
@@ -146,59 +139,38 @@ pub fn part2<const N: usize>(input: &ParsedInput<N>) -> impl Display {
             let mut left = 0;
             for i in (0..x).rev() {
                 left += 1;
-                if field[y][i].height >= field[y][x].height {        
+                if field[(i, y)].height >= field[(x, y)].height {        
                     break;
                 }
             }
 
-            scores[y][x] = up * left * right * down;
+            scores[(x, y)] = up * left * right * down;
         }
     }
 
     // println!("{}", scores[1][2]);
     // println!("{}", scores[3][2]);
     // Get highest entry in scores array 
-    scores.into_iter()
-    .map(|r| 
-        r.into_iter()
-        .max()
-        .unwrap()
-    ).max().unwrap()
+    scores.cells().iter()
+    .max()
+    .copied()
+    .unwrap()
 }
 
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input::<5>(&read_file("sample"));
-    let input = parse_input::<99>(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "21";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "8";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
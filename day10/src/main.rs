@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use aoc_common::{parse, ParseError};
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
 #[derive(Debug)]
@@ -8,14 +10,14 @@ pub enum Instr {
     NoOp,
 }
 
-type ParsedInput = Vec<Instr>;
+pub type ParsedInput = Vec<Instr>;
 
-pub fn parse_input(input: &str) -> ParsedInput {
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
     input.lines().map(|l|
         match l.split_once(' ') {
-            Some(("addx", amt)) => Instr::Add(amt.parse::<i32>().expect("expected amount")),
-            None => Instr::NoOp,
-            Some((l1, l2)) => panic!("unexpected instruction! ({} {})", l1, l2) 
+            Some(("addx", amt)) => Ok(Instr::Add(parse::from_str(input, amt, "an amount to add")?)),
+            None if l == "noop" => Ok(Instr::NoOp),
+            _ => Err(ParseError::at(input, l, "an instruction (`addx` or `noop`)")),
         }
     )
     .collect()
@@ -81,42 +83,17 @@ pub fn part2(input: &ParsedInput) -> impl Display {
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-    let input = parse_input(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "13140";
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "██  ██  ██  ██  ██  ██  ██  ██  ██  ██  
-███   ███   ███   ███   ███   ███   ███ 
-████    ████    ████    ████    ████    
-█████     █████     █████     █████     
-██████      ██████      ██████      ████
-███████       ███████       ███████     
-";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
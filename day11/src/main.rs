@@ -1,5 +1,7 @@
 use std::{fmt::Display, cell::{RefCell, RefMut}};
 
+use aoc_common::{parse, ParseError};
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
 #[derive(Clone, Copy, Debug)]
@@ -86,33 +88,45 @@ impl Monkey {
 }
 
 
-type ParsedInput = Vec<RefCell<Monkey>>;
+pub type ParsedInput = Vec<RefCell<Monkey>>;
 
-pub fn parse_input(input: &str) -> ParsedInput {
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
     input.split("\n\n")
     .map(|m| {
-        let lines = m.lines().collect::<Vec<&str>>();
+        let mut lines = m.lines();
+
+        // Take the next line of the monkey, which should start with `prefix`,
+        // and return the rest of it
+        let mut field = |prefix: &str| {
+            let l = lines.next().ok_or_else(|| ParseError::at(input, &m[m.len()..], format!("`{}`", prefix.trim())))?;
+            let trimmed = l.trim_start();
+            trimmed.strip_prefix(prefix).ok_or_else(|| ParseError::at(input, trimmed, format!("`{}`", prefix.trim())))
+        };
+
+        field("Monkey ")?;
 
-        let starting_items = lines[1][18..].split(", ")
+        let starting_items = field("Starting items: ")?.split(", ")
         .map(|it|
-            it.parse::<i64>().expect("item parsing error")
+            parse::from_str::<i64>(input, it, "a worry level")
         )
-        .collect::<Vec<i64>>();
+        .collect::<Result<Vec<i64>, ParseError>>()?;
 
-        let update_split = lines[2].split(" ").collect::<Vec<&str>>();
-        let update = match (update_split[6], update_split[7].parse::<i64>()) {
+        let operation = field("Operation: new = old ")?;
+        let (operator, operand) = parse::split_once(input, operation, " ")?;
+        let update = match (operator, operand.parse::<i64>()) {
             ("+", Ok(const_val)) => Op::AddConst(const_val),
-            ("+", _err) => Op::AddSelf,
+            ("+", _err) if operand == "old" => Op::AddSelf,
             ("*", Ok(const_val)) => Op::MulConst(const_val),
-            ("*", _err) => Op::MulSelf,
-            _ => panic!("unexpected operator! {} ; {} ; {}", lines[2], update_split[6], update_split[7])
+            ("*", _err) if operand == "old" => Op::MulSelf,
+            ("+" | "*", _err) => return Err(ParseError::at(input, operand, "a number or `old`")),
+            _ => return Err(ParseError::at(input, operator, "an operator (`+` or `*`)")),
         };
 
-        let test = lines[3].rsplit_once(" ").unwrap().1.parse::<i64>().expect("test");
-        let true_monkey = lines[4].rsplit_once(" ").unwrap().1.parse::<usize>().expect("true_monkey");
-        let false_monkey = lines[5].rsplit_once(" ").unwrap().1.parse::<usize>().expect("false_monkey");
+        let test = parse::from_str(input, field("Test: divisible by ")?, "a divisor")?;
+        let true_monkey = parse::from_str(input, field("If true: throw to monkey ")?, "a monkey number")?;
+        let false_monkey = parse::from_str(input, field("If false: throw to monkey ")?, "a monkey number")?;
 
-        RefCell::new(Monkey::new(starting_items, update, test, true_monkey, false_monkey))
+        Ok(RefCell::new(Monkey::new(starting_items, update, test, true_monkey, false_monkey)))
     })
     .collect()
 }
@@ -165,36 +179,17 @@ pub fn part2(input: &ParsedInput) -> impl Display {
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-    let input = parse_input(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "10605";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "2713310158";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
@@ -1,7 +1,8 @@
 use std::{fmt::Display, ops::Deref, cmp::Ordering};
 use pest_derive::Parser;
-use pest::{Parser, iterators::Pair};
+use pest::{Parser, error::InputLocation, iterators::Pair};
 
+use aoc_common::{parse, ParseError};
 
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
@@ -46,14 +47,14 @@ impl Deref for List {
     }
 }
 
-type ParsedInput = Vec<List>;
+pub type ParsedInput = Vec<List>;
 
-pub fn parse_list(pair: Pair<Rule>) -> List {
+pub fn parse_list(input: &str, pair: Pair<Rule>) -> Result<List, ParseError> {
     let mut list = List::new();
     for r in pair.into_inner() {
         match r.as_rule() {
-            Rule::num => list.add_integer(r.as_str().parse().expect("Expected num")),
-            Rule::list => list.add_list(parse_list(r)),
+            Rule::num => list.add_integer(parse::from_str(input, r.as_str(), "a number that fits in 32 bits")?),
+            Rule::list => list.add_list(parse_list(input, r)?),
             Rule::LPAREN |
             Rule::RPAREN |
             Rule::lists |
@@ -63,23 +64,44 @@ pub fn parse_list(pair: Pair<Rule>) -> List {
         }
     }
 
-    list
+    Ok(list)
 }
 
-pub fn parse_input(input: &str) -> ParsedInput {
-    let mut pairs = ListPairParser::parse(Rule::lists, input).unwrap_or_else(|e| panic!("{}", e));
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    let mut pairs = ListPairParser::parse(Rule::lists, input).map_err(|e| {
+        // Describe the rules pest expected in words, and point at where it
+        // expected them
+        let e = e.renamed_rules(|r| String::from(match r {
+            Rule::num => "a number",
+            Rule::list => "a list",
+            Rule::lists => "a list of packets",
+            Rule::LPAREN => "`[`",
+            Rule::RPAREN => "`]`",
+            Rule::EOI => "the end of the input",
+            Rule::WHITESPACE => "a space",
+            Rule::newline => "a newline",
+        }));
+        let offset = match e.location {
+            InputLocation::Pos(pos) => pos,
+            InputLocation::Span((start, _)) => start,
+        };
+        let message = e.variant.message();
+        ParseError::at_offset(input, offset, message.strip_prefix("expected ").unwrap_or(&message))
+    })?;
     let root = pairs.next().unwrap();
-    root.into_inner().map(|r| parse_list(r)).collect::<Vec<List>>()
+    root.into_inner().map(|r| parse_list(input, r)).collect::<Result<Vec<List>, ParseError>>()
 }
 
 pub fn rec_comp(l1: &List, l2: &List) -> Ordering {
     for (l, r) in l1.iter().zip(l2.iter()) {
-        match (match (l, r) {
-            (ListItem::Integer(li), ListItem::Integer(ri)) => li.cmp(&ri),
+        let ord = match (l, r) {
+            (ListItem::Integer(li), ListItem::Integer(ri)) => li.cmp(ri),
             (ListItem::Integer(li), ListItem::List(rl)) => rec_comp(&List::from_item(ListItem::Integer(*li)), rl),
             (ListItem::List(ll), ListItem::Integer(ri)) => rec_comp(ll, &List::from_item(ListItem::Integer(*ri))),
             (ListItem::List(ll), ListItem::List(rl)) => rec_comp(ll, rl),
-        }) {
+        };
+
+        match ord {
             Ordering::Less => return Ordering::Less,
             Ordering::Greater => return Ordering::Greater,
             Ordering::Equal => (),
@@ -117,36 +139,17 @@ pub fn part2(input: &ParsedInput) -> impl Display {
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-    let input = parse_input(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "13";
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "140";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
@@ -1,17 +1,92 @@
+// `main.rs` doubles as the binary of this day, which prints the answers of
+// both parts, and as the module holding the challenge logic.
+#![allow(special_module_name)]
+
 extern crate fxhash;
 
 use std::fmt::Display;
 
+use aoc_common::{ParseError, Solution};
+
 mod main;
 
-pub fn part1() -> impl Display {
-    let raw_input = include_str!("../input");
-    let parsed_input = main::parse_input(raw_input);
-    main::part1(&parsed_input)
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled.
+#[cfg(feature = "embedded-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input"));
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled.
+#[cfg(not(feature = "embedded-input"))]
+pub const INPUT: Option<&str> = None;
+
+/// Day 14: Regolith Reservoir
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Regolith Reservoir";
+    const INPUT: Option<&'static str> = INPUT;
+
+    type Parsed = main::ParsedInput;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        main::parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> impl Display {
+        main::part1(input)
+    }
+
+    fn part2(input: &Self::Parsed) -> impl Display {
+        main::part2(input)
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<String, ParseError> {
+    Ok(Day14::part1(&Day14::parse(input)?).to_string())
 }
 
-pub fn part2() -> impl Display {
-    let raw_input = include_str!("../input");
-    let parsed_input = main::parse_input(raw_input);
-    main::part2(&parsed_input)
-}
\ No newline at end of file
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    Ok(Day14::part2(&Day14::parse(input)?).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::{main, Day14};
+
+    const SAMPLE: &str = include_str!("../sample");
+    const INPUT: &str = include_str!("../input");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(main::part1(&main::parse_input(SAMPLE).unwrap()).to_string(), "24");
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(Day14::part1(&Day14::parse(INPUT).unwrap()).to_string(), "825");
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(main::part2(&main::parse_input(SAMPLE).unwrap()).to_string(), "93");
+    }
+
+    #[test]
+    fn part2_input() {
+        assert_eq!(Day14::part2(&Day14::parse(INPUT).unwrap()).to_string(), "26729");
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = main::parse_input("498,4 -> 498,6 -> 496,6\n503,4 -> 502,x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 14));
+        assert_eq!(error.expected, "a y coordinate");
+
+        let error = main::parse_input("498,4 -> 500,6\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 10));
+    }
+}
@@ -1,114 +1,67 @@
 use std::fmt::Display;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
 use fxhash::FxHashMap as HashMap;
 
+use aoc_common::{parse, Coord2, ParseError};
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub struct Coord(i32, i32);
-
-impl Add<Coord> for Coord {
-    type Output = Coord;
-
-    fn add(mut self, rhs: Coord) -> Self::Output {
-        self += rhs;
-        self
-    }
-}
-
-impl AddAssign<Coord> for Coord {
-    fn add_assign(&mut self, rhs: Coord) {
-        self.0 += rhs.0;
-        self.1 += rhs.1;
-    }
-}
-
-impl Sub<Coord> for Coord {
-    type Output = Coord;
-
-    fn sub(mut self, rhs: Coord) -> Self::Output {
-        self -= rhs;
-        self
-    }
-}
-
-impl SubAssign<Coord> for Coord {
-    fn sub_assign(&mut self, rhs: Coord) {
-        self.0 -= rhs.0;
-        self.1 -= rhs.1;
-    }
-}
-
-
 #[derive(Copy, Clone, Debug)]
 pub enum State {
     Rock,
     Sand
 }
 
-type Board = HashMap<Coord, State>;
-type ParsedInput = (Board, i32);
+type Board = HashMap<Coord2, State>;
+pub type ParsedInput = (Board, i32);
 
-pub fn parse_input(input: &str) -> ParsedInput {
-    let paths = input.lines().map(|l|
-        l.split(" -> ")
-        .map(|coord|
-            match coord.split_once(",") {
-                Some((x, y)) => Coord(x.parse::<i32>().unwrap(), y.parse::<i32>().unwrap()),
-                None => panic!("Expected coord")
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    let paths = input.lines().map(|l| {
+        let mut path: Vec<Coord2> = Vec::new();
+        for coord in l.split(" -> ") {
+            let (x, y) = parse::split_once(input, coord, ",")?;
+            let c = Coord2::new(parse::from_str(input, x, "an x coordinate")?, parse::from_str(input, y, "a y coordinate")?);
+
+            // Paths only consist of horizontal and vertical lines
+            if path.last().is_some_and(|prev| prev.x() != c.x() && prev.y() != c.y()) {
+                return Err(ParseError::at(input, coord, "a point in line with the previous point"));
             }
-        )
-        .collect::<Vec<Coord>>()
-    )
-    .collect::<Vec<Vec<Coord>>>();
+            path.push(c);
+        }
+        Ok(path)
+    })
+    .collect::<Result<Vec<Vec<Coord2>>, ParseError>>()?;
 
     // Construct Board
-    let mut board: HashMap<Coord, State> = HashMap::default();
+    let mut board: HashMap<Coord2, State> = HashMap::default();
     board.reserve(1024);
 
     // Get y range of input & insert rock into board
     let mut max_y = 0;
     for path in paths {
         for coord in path.windows(2) {
-            let (x1, y1) = (coord[0].0, coord[0].1);
-            let (x2, y2) = (coord[1].0, coord[1].1);
-            match (x1 - x2, y1 - y2) {
-                (0, delta) if delta > 0 => {
-                    for i in 0..=delta {
-                        board.insert(Coord(x1, y2 + i), State::Rock);
-                    }
-                },
-                (0, delta) if delta <= 0 => {
-                    for i in 0..=delta.abs() {
-                        board.insert(Coord(x1, y1 + i), State::Rock);
-                    }
-                },
-                (delta, 0) if delta > 0 => {
-                    for i in 0..=delta {
-                        board.insert(Coord(x2 + i, y1), State::Rock);
-                    }
-                },
-                (delta, 0) if delta <= 0 => {
-                    for i in 0..=delta.abs() {
-                        board.insert(Coord(x1 + i, y1), State::Rock);
-                    }
-                },
-                (x, y) => panic!("Panic! Got ({},{})", x, y),
+            // Walk from one end of the line to the other
+            let (from, to) = (coord[0], coord[1]);
+            let step = (to - from).signum();
+            let mut rock = from;
+            board.insert(rock, State::Rock);
+            while rock != to {
+                rock += step;
+                board.insert(rock, State::Rock);
             }
-            max_y = max_y.max(y1.max(y2));
+            max_y = max_y.max(from.y().max(to.y()));
         }
     }
 
     // Return board and max_y
-    (board, max_y)
+    Ok((board, max_y))
 }
 
 pub fn part1(input: &ParsedInput) -> impl Display {
     let (mut board, max_y) = (input.0.clone(), input.1);
 
     // Add a new sand particle
-    let mut history: Vec<Coord> = Vec::with_capacity(1024);
-    let mut sand = Coord(500, 0);
+    let mut history: Vec<Coord2> = Vec::with_capacity(1024);
+    let mut sand = Coord2::new(500, 0);
 
     let mut counter = 0;
     'falling: loop {
@@ -116,11 +69,11 @@ pub fn part1(input: &ParsedInput) -> impl Display {
         counter += 1;
 
         // Let it fall down
-        while sand.1 < max_y {
+        while sand.y() < max_y {
             // Compute bottom, bottom-left and bottom-right positions.
-            let bottom = sand + Coord(0, 1);
-            let left = sand + Coord(-1, 1);
-            let right = sand + Coord(1, 1);
+            let bottom = sand + Coord2::new(0, 1);
+            let left = sand + Coord2::new(-1, 1);
+            let right = sand + Coord2::new(1, 1);
 
             // See what is in the bottom-left, bottom, and bottom-right positions.
             match (board.get(&left), board.get(&bottom), board.get(&right)) {
@@ -149,8 +102,8 @@ pub fn part2(input: &ParsedInput) -> impl Display {// Construct Board
     let (mut board, max_y) = (input.0.clone(), input.1);
 
     // Add a new sand particle
-    let mut history: Vec<Coord> = Vec::with_capacity(1024);
-    let mut sand = Coord(500, 0);
+    let mut history: Vec<Coord2> = Vec::with_capacity(1024);
+    let mut sand = Coord2::new(500, 0);
 
     let mut counter = 0;
     'falling: loop {
@@ -158,16 +111,16 @@ pub fn part2(input: &ParsedInput) -> impl Display {// Construct Board
         counter += 1;
         
         // Check whether origin is still free
-        if board.get(&sand).is_some() {
+        if board.contains_key(&sand) {
             return counter - 1;
         }
 
         // Let it fall down
-        while sand.1 < max_y + 1 {
+        while sand.y() < max_y + 1 {
             // Compute bottom, bottom-left and bottom-right positions.
-            let bottom = sand + Coord(0, 1);
-            let left = sand + Coord(-1, 1);
-            let right = sand + Coord(1, 1);
+            let bottom = sand + Coord2::new(0, 1);
+            let left = sand + Coord2::new(-1, 1);
+            let right = sand + Coord2::new(1, 1);
 
             // See what is in the bottom-left, bottom, and bottom-right positions.
             match (board.get(&left), board.get(&bottom), board.get(&right)) {
@@ -177,7 +130,7 @@ pub fn part2(input: &ParsedInput) -> impl Display {// Construct Board
                 (Some(_), Some(_), Some(_)) => { 
                     // If there is something in all positions, insert a sand particle at the current position
                     board.insert(sand, State::Sand);
-                    sand = history.pop().unwrap_or(Coord(500, 0));
+                    sand = history.pop().unwrap_or(Coord2::new(500, 0));
 
                     // Drop in a new sand particle
                     continue 'falling;
@@ -188,43 +141,24 @@ pub fn part2(input: &ParsedInput) -> impl Display {// Construct Board
         // If the for-loop terminated successfully, it means the sand particle
         // has landed on the floor, add it there
         board.insert(sand, State::Sand);
-        sand = history.pop().unwrap_or(Coord(500, 0));
+        sand = history.pop().unwrap_or(Coord2::new(500, 0));
     }
 }
 
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-    let input = parse_input(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "24";
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "93";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
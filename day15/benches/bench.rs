@@ -11,12 +11,12 @@ fn bench_main(c: &mut Criterion) {
     });
 
     c.bench_function("part 1 (sample)", |b| {
-        let input = main::parse_input(&main::read_file("sample"));
+        let input = main::parse_input(&main::read_file("sample")).unwrap();
         b.iter(|| main::part1(black_box(&input), 10))
     });
 
     c.bench_function("part 2 (sample)", |b| {
-        let input = main::parse_input(&main::read_file("sample"));
+        let input = main::parse_input(&main::read_file("sample")).unwrap();
         b.iter(|| main::part2(black_box(&input), 20, 20))
     });
 
@@ -26,12 +26,12 @@ fn bench_main(c: &mut Criterion) {
     });
 
     c.bench_function("part 1 (real)", |b| {
-        let input = main::parse_input(&main::read_file("input"));
+        let input = main::parse_input(&main::read_file("input")).unwrap();
         b.iter(|| main::part1(black_box(&input), 2_000_000))
     });
     
     c.bench_function("part 2 (real)", |b| {
-        let input = main::parse_input(&main::read_file("input"));
+        let input = main::parse_input(&main::read_file("input")).unwrap();
         b.iter(|| main::part2(black_box(&input), 4_000_000, 4_000_000))
     });
 }
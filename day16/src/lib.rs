@@ -0,0 +1,77 @@
+// `main.rs` doubles as the binary of this day, which prints the answers of
+// both parts, and as the module holding the challenge logic.
+#![allow(special_module_name)]
+
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+
+mod main;
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(feature = "embedded-input")]
+pub const INPUT: Option<&str> = {
+    let input = include_str!("../input");
+    if input.is_empty() { None } else { Some(input) }
+};
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(not(feature = "embedded-input"))]
+pub const INPUT: Option<&str> = None;
+
+/// Day 16: Proboscidea Volcanium
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Proboscidea Volcanium";
+    const INPUT: Option<&'static str> = INPUT;
+
+    type Parsed = main::ParsedInput;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        main::parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> impl Display {
+        main::part1(input)
+    }
+
+    fn part2(input: &Self::Parsed) -> impl Display {
+        main::part2(input)
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<String, ParseError> {
+    Ok(Day16::part1(&Day16::parse(input)?).to_string())
+}
+
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    Ok(Day16::part2(&Day16::parse(input)?).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::main;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(main::part1(&main::parse_input(SAMPLE).unwrap()).to_string(), "1651");
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(main::part2(&main::parse_input(SAMPLE).unwrap()).to_string(), "1707");
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = main::parse_input("Valve AA has flow rate=0; tunnels lead to valves BB\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 50));
+        assert_eq!(error.expected, "the name of a valve");
+    }
+}
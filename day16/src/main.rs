@@ -0,0 +1,149 @@
+use std::fmt::Display;
+use std::collections::HashMap;
+
+use aoc_common::{parse, ParseError};
+
+// ========================= Challenge Logic ============================
+// Define your own output type here for the `parse_input` function.
+
+/// Most valves with a positive flow rate there can be, as every set of opened
+/// valves gets an entry in a table.
+const MAX_VALVES: usize = 20;
+
+/// The valves with a positive flow rate, and the time it takes to walk between
+/// them. All other valves only matter for the distances, so they are left out.
+#[derive(Clone, Debug)]
+pub struct Valves {
+    flows: Vec<u32>,
+    /// Minutes to walk from one valve to another, the last row being from the
+    /// starting valve `AA`.
+    distances: Vec<Vec<u32>>,
+}
+
+pub type ParsedInput = Valves;
+
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    let mut valves: Vec<(&str, u32, Vec<&str>)> = Vec::new();
+    for l in input.lines() {
+        let rest = l.strip_prefix("Valve ").ok_or_else(|| ParseError::at(input, l, "`Valve`"))?;
+        let (name, rest) = parse::split_once(input, rest, " has flow rate=")?;
+        let (flow, tunnels) = parse::split_once(input, rest, "; ")?;
+        let tunnels = ["tunnels lead to valves ", "tunnel leads to valve "].iter()
+            .find_map(|prefix| tunnels.strip_prefix(prefix))
+            .ok_or_else(|| ParseError::at(input, tunnels, "`tunnels lead to valves`"))?;
+        valves.push((name, parse::from_str(input, flow, "a flow rate")?, tunnels.split(", ").collect()));
+    }
+
+    let index = valves.iter().enumerate().map(|(i, &(name, _, _))| (name, i)).collect::<HashMap<&str, usize>>();
+    let start = *index.get("AA").ok_or_else(|| ParseError::at_end(input, "a line for valve `AA`"))?;
+
+    // Shortest walks between all valves (Floyd-Warshall)
+    let n = valves.len();
+    let mut dist = vec![vec![u32::MAX / 2; n]; n];
+    for (i, (_, _, tunnels)) in valves.iter().enumerate() {
+        dist[i][i] = 0;
+        for tunnel in tunnels {
+            let j = *index.get(tunnel).ok_or_else(|| ParseError::at(input, tunnel, "the name of a valve"))?;
+            dist[i][j] = 1;
+        }
+    }
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                dist[i][j] = dist[i][j].min(dist[i][k] + dist[k][j]);
+            }
+        }
+    }
+
+    // Only keep the valves worth opening, and the start
+    let useful = (0..n).filter(|&i| valves[i].1 > 0).collect::<Vec<usize>>();
+    if useful.len() > MAX_VALVES {
+        return Err(ParseError::at_end(input, format!("at most {} valves with a positive flow rate", MAX_VALVES)));
+    }
+    let rows = useful.iter().chain([&start]);
+    Ok(Valves {
+        flows: useful.iter().map(|&i| valves[i].1).collect(),
+        distances: rows.map(|&i| useful.iter().map(|&j| dist[i][j]).collect()).collect(),
+    })
+}
+
+impl Valves {
+    /// Most pressure that can be released in `minutes` by opening exactly the
+    /// valves in every set, indexed by the bitmask of the set.
+    pub fn best_per_set(&self, minutes: u32) -> Vec<u32> {
+        let mut best = vec![0; 1 << self.flows.len()];
+        let mut seen = HashMap::new();
+        self.explore(self.flows.len(), minutes, 0, 0, &mut best, &mut seen);
+        best
+    }
+
+    /// Open valves from `at` with `minutes` left, having opened the set
+    /// `opened` and released `released` so far.
+    ///
+    /// What can still be released only depends on `(at, minutes, opened)`, so
+    /// `seen` memoises the most released on reaching every such state, and
+    /// states that were reached before with at least as much are cut off.
+    fn explore(&self, at: usize, minutes: u32, opened: usize, released: u32, best: &mut [u32], seen: &mut HashMap<(usize, u32, usize), u32>) {
+        match seen.get_mut(&(at, minutes, opened)) {
+            Some(most) if *most >= released => return,
+            Some(most) => *most = released,
+            None => { seen.insert((at, minutes, opened), released); },
+        }
+        best[opened] = best[opened].max(released);
+
+        for (next, &flow) in self.flows.iter().enumerate() {
+            // Walk there and open the valve, if there is time left after that
+            let time = self.distances[at][next] + 1;
+            if opened & 1 << next == 0 && time < minutes {
+                let left = minutes - time;
+                self.explore(next, left, opened | 1 << next, released + flow * left, best, seen);
+            }
+        }
+    }
+}
+
+pub fn part1(input: &ParsedInput) -> impl Display {
+    input.best_per_set(30).into_iter().max().unwrap_or(0)
+}
+
+pub fn part2(input: &ParsedInput) -> impl Display {
+    let best = input.best_per_set(26);
+    let all = best.len() - 1;
+
+    // Best of any subset of every set, so the elephant can take the best of
+    // whatever valves are left over
+    let mut within = best.clone();
+    for valve in 0..input.flows.len() {
+        for set in 0..best.len() {
+            if set & 1 << valve != 0 {
+                within[set] = within[set].max(within[set ^ 1 << valve]);
+            }
+        }
+    }
+
+    (0..best.len())
+    .map(|mine| best[mine] + within[all ^ mine])
+    .max().unwrap_or(0)
+}
+
+// =========================== Main Function ============================
+#[allow(dead_code)]
+fn main() {
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
+
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
+    formatted_print("1", part1(&input));
+    formatted_print("2", part2(&input));
+}
+
+pub fn read_file(file_name: &str) -> String {
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
+}
+
+fn formatted_print<T : Display>(part: &str, output: T) {
+    println!("==================== Part {} ======================", part);
+    println!("{}", output);
+    println!("==================================================");
+}
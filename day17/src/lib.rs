@@ -0,0 +1,98 @@
+// `main.rs` doubles as the binary of this day, which prints the answers of
+// both parts, and as the module holding the challenge logic.
+#![allow(special_module_name)]
+
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+
+mod main;
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(feature = "embedded-input")]
+pub const INPUT: Option<&str> = {
+    let input = include_str!("../input");
+    if input.is_empty() { None } else { Some(input) }
+};
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(not(feature = "embedded-input"))]
+pub const INPUT: Option<&str> = None;
+
+/// Day 17: Pyroclastic Flow
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Pyroclastic Flow";
+    const INPUT: Option<&'static str> = INPUT;
+
+    type Parsed = main::ParsedInput;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        main::parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> impl Display {
+        main::part1(input)
+    }
+
+    fn part2(input: &Self::Parsed) -> impl Display {
+        main::part2(input)
+    }
+}
+
+/// The chamber, for drawing the top of the tower while debugging.
+pub use main::{Chamber, Jet};
+
+pub fn solve_part1(input: &str) -> Result<String, ParseError> {
+    Ok(Day17::part1(&Day17::parse(input)?).to_string())
+}
+
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    Ok(Day17::part2(&Day17::parse(input)?).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::main;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(main::part1(&main::parse_input(SAMPLE).unwrap()).to_string(), "3068");
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(main::part2(&main::parse_input(SAMPLE).unwrap()).to_string(), "1514285714288");
+    }
+
+    #[test]
+    fn render_top() {
+        let jets = main::parse_input(SAMPLE).unwrap();
+        let mut chamber = main::Chamber::new(&jets);
+        chamber.drop_rock();
+        chamber.drop_rock();
+        assert_eq!(chamber.render_top(5), "|...#...|\n|..###..|\n|...#...|\n|..####.|\n+-------+\n");
+        assert_eq!(chamber.render_top(2), "|...#...|\n|..###..|\n");
+
+        // Only the growth after the first few rocks repeats, so the short
+        // towers must match a plain simulation
+        for rocks in [10, 100] {
+            let mut chamber = main::Chamber::new(&jets);
+            (0..rocks).for_each(|_| chamber.drop_rock());
+            assert_eq!(main::tower_height(&jets, rocks), chamber.height());
+        }
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = main::parse_input(">><>x<\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        assert!(main::parse_input("\n").is_err());
+    }
+}
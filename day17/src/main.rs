@@ -0,0 +1,201 @@
+use std::fmt::Display;
+use std::collections::HashMap;
+
+use aoc_common::ParseError;
+
+// ========================= Challenge Logic ============================
+// Define your own output type here for the `parse_input` function.
+
+/// Width of the chamber.
+const WIDTH: usize = 7;
+
+/// The rocks in the order they fall, as rows from bottom to top. The leftmost
+/// column of the chamber is the highest of the seven bits of a row, and every
+/// rock starts two columns from the left wall.
+const ROCKS: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+pub type ParsedInput = Vec<Jet>;
+
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    let line = input.trim_end();
+    let jets = line.char_indices().map(|(i, c)| match c {
+        '<' => Ok(Jet::Left),
+        '>' => Ok(Jet::Right),
+        _ => Err(ParseError::at(input, &line[i..], "`<` or `>`")),
+    }).collect::<Result<Vec<Jet>, ParseError>>()?;
+
+    if jets.is_empty() {
+        return Err(ParseError::at_end(input, "`<` or `>`"));
+    }
+    Ok(jets)
+}
+
+/// Chamber the rocks fall into, with a row of seven bits per level of the
+/// settled rocks, from the floor up.
+#[derive(Clone, Debug)]
+pub struct Chamber<'a> {
+    jets: &'a [Jet],
+    rows: Vec<u8>,
+    rocks: usize,
+    jet: usize,
+}
+
+impl<'a> Chamber<'a> {
+    pub fn new(jets: &'a [Jet]) -> Chamber<'a> {
+        Chamber { jets, rows: Vec::new(), rocks: 0, jet: 0 }
+    }
+
+    /// Height of the tower of settled rocks.
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Let the next rock fall until it comes to rest.
+    pub fn drop_rock(&mut self) {
+        let mut rock = ROCKS[self.rocks % ROCKS.len()].to_vec();
+        let mut bottom = self.rows.len() + 3;
+
+        loop {
+            let pushed = match self.jets[self.jet] {
+                Jet::Left if rock.iter().all(|row| row & 1 << (WIDTH - 1) == 0) => rock.iter().map(|row| row << 1).collect(),
+                Jet::Right if rock.iter().all(|row| row & 1 == 0) => rock.iter().map(|row| row >> 1).collect(),
+                _ => rock.clone(),
+            };
+            self.jet = (self.jet + 1) % self.jets.len();
+            if self.fits(&pushed, bottom) {
+                rock = pushed;
+            }
+
+            if bottom == 0 || !self.fits(&rock, bottom - 1) {
+                break;
+            }
+            bottom -= 1;
+        }
+
+        for (i, row) in rock.into_iter().enumerate() {
+            match self.rows.get_mut(bottom + i) {
+                Some(settled) => *settled |= row,
+                None => self.rows.push(row),
+            }
+        }
+        self.rocks += 1;
+    }
+
+    /// Whether `rock` overlaps no settled rocks with its bottom row at height
+    /// `bottom`.
+    fn fits(&self, rock: &[u8], bottom: usize) -> bool {
+        rock.iter().enumerate().all(|(i, row)| self.rows.get(bottom + i).is_none_or(|settled| settled & row == 0))
+    }
+
+    /// Everything that decides how the tower grows from here: the next rock,
+    /// the next jet, and how far down every column is open from the top.
+    fn state(&self) -> (usize, usize, [usize; WIDTH]) {
+        let mut depths = [self.rows.len(); WIDTH];
+        for (column, depth) in depths.iter_mut().enumerate() {
+            let bit = 1 << (WIDTH - 1 - column);
+            if let Some(top) = self.rows.iter().rposition(|row| row & bit != 0) {
+                *depth = self.rows.len() - 1 - top;
+            }
+        }
+        (self.rocks % ROCKS.len(), self.jet, depths)
+    }
+
+    /// Drawing of the top `rows` levels of the tower, like the ones in the
+    /// puzzle, including the floor once it is in view.
+    pub fn render_top(&self, rows: usize) -> String {
+        let mut drawing = String::new();
+        for row in self.rows.iter().rev().take(rows) {
+            drawing.push('|');
+            for column in 0..WIDTH {
+                drawing.push(if row & 1 << (WIDTH - 1 - column) != 0 { '#' } else { '.' });
+            }
+            drawing.push_str("|\n");
+        }
+        if rows > self.rows.len() {
+            drawing.push_str("+-------+\n");
+        }
+        drawing
+    }
+}
+
+/// Height of the tower after `rocks` rocks have fallen.
+///
+/// Once the chamber is in a state it was in before, the tower grows the same
+/// way again and again, so all full repetitions are skipped.
+pub fn tower_height(jets: &[Jet], rocks: usize) -> usize {
+    let mut chamber = Chamber::new(jets);
+    let mut seen = HashMap::new();
+    let mut skipped = 0;
+
+    while chamber.rocks < rocks {
+        chamber.drop_rock();
+        if skipped > 0 {
+            continue;
+        }
+
+        if let Some((before, height)) = seen.insert(chamber.state(), (chamber.rocks, chamber.height())) {
+            let period = chamber.rocks - before;
+            let repeats = (rocks - chamber.rocks) / period;
+            skipped = repeats * (chamber.height() - height);
+            chamber.rocks += repeats * period;
+        }
+    }
+
+    chamber.height() + skipped
+}
+
+pub fn part1(input: &ParsedInput) -> impl Display {
+    tower_height(input, 2022)
+}
+
+pub fn part2(input: &ParsedInput) -> impl Display {
+    tower_height(input, 1_000_000_000_000)
+}
+
+// =========================== Main Function ============================
+/// Levels of the tower drawn by `--render`.
+const RENDER_ROWS: usize = 20;
+
+#[allow(dead_code)]
+fn main() {
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
+
+    // `--render N` draws the top of the tower after N rocks instead, for
+    // debugging
+    let mut args = std::env::args().skip(1);
+    if let (Some("--render"), Some(rocks)) = (args.next().as_deref(), args.next()) {
+        let rocks = rocks.parse::<usize>().unwrap_or_else(|_| panic!("Invalid number of rocks: {}", rocks));
+        let mut chamber = Chamber::new(&input);
+        (0..rocks).for_each(|_| chamber.drop_rock());
+        print!("{}", chamber.render_top(RENDER_ROWS));
+        return;
+    }
+
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
+    formatted_print("1", part1(&input));
+    formatted_print("2", part2(&input));
+}
+
+pub fn read_file(file_name: &str) -> String {
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
+}
+
+fn formatted_print<T : Display>(part: &str, output: T) {
+    println!("==================== Part {} ======================", part);
+    println!("{}", output);
+    println!("==================================================");
+}
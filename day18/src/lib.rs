@@ -0,0 +1,77 @@
+// `main.rs` doubles as the binary of this day, which prints the answers of
+// both parts, and as the module holding the challenge logic.
+#![allow(special_module_name)]
+
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+
+mod main;
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(feature = "embedded-input")]
+pub const INPUT: Option<&str> = {
+    let input = include_str!("../input");
+    if input.is_empty() { None } else { Some(input) }
+};
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(not(feature = "embedded-input"))]
+pub const INPUT: Option<&str> = None;
+
+/// Day 18: Boiling Boulders
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Boiling Boulders";
+    const INPUT: Option<&'static str> = INPUT;
+
+    type Parsed = main::ParsedInput;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        main::parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> impl Display {
+        main::part1(input)
+    }
+
+    fn part2(input: &Self::Parsed) -> impl Display {
+        main::part2(input)
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<String, ParseError> {
+    Ok(Day18::part1(&Day18::parse(input)?).to_string())
+}
+
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    Ok(Day18::part2(&Day18::parse(input)?).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::main;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(main::part1(&main::parse_input(SAMPLE).unwrap()).to_string(), "64");
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(main::part2(&main::parse_input(SAMPLE).unwrap()).to_string(), "58");
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = main::parse_input("2,2,2\n1,2\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.expected, "`,`");
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt::Display;
+
+use aoc_common::{parse, Coord3, ParseError, VoxelSet};
+
+// ========================= Challenge Logic ============================
+// Define your own output type here for the `parse_input` function.
+pub type ParsedInput = VoxelSet<3>;
+
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    input.lines()
+    .map(|l| {
+        let (x, rest) = parse::split_once(input, l, ",")?;
+        let (y, z) = parse::split_once(input, rest, ",")?;
+        let coordinate = |s| parse::from_str::<i32>(input, s, "a 32-bit coordinate");
+        Ok(Coord3::new(coordinate(x)?, coordinate(y)?, coordinate(z)?))
+    })
+    .collect()
+}
+
+pub fn part1(input: &ParsedInput) -> impl Display {
+    input.surface_area()
+}
+
+pub fn part2(input: &ParsedInput) -> impl Display {
+    input.exterior_surface_area()
+}
+
+// =========================== Main Function ============================
+#[allow(dead_code)]
+fn main() {
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
+
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
+    formatted_print("1", part1(&input));
+    formatted_print("2", part2(&input));
+}
+
+pub fn read_file(file_name: &str) -> String {
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
+}
+
+fn formatted_print<T : Display>(part: &str, output: T) {
+    println!("==================== Part {} ======================", part);
+    println!("{}", output);
+    println!("==================================================");
+}
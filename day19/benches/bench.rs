@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../src/main.rs"]
+mod main;
+
+fn bench_main(c: &mut Criterion) {
+    c.bench_function("parse input (sample)", |b| {
+        let file = main::read_file("sample");
+        b.iter(|| main::parse_input(black_box(&file)))
+    });
+
+    c.bench_function("part 1 (sample)", |b| {
+        let input = main::parse_input(&main::read_file("sample")).unwrap();
+        b.iter(|| main::part1(black_box(&input)))
+    });
+
+    c.bench_function("part 2 (sample)", |b| {
+        let input = main::parse_input(&main::read_file("sample")).unwrap();
+        b.iter(|| main::part2(black_box(&input)))
+    });
+
+    // Without the puzzle input there is only the sample to benchmark
+    if main::read_file("input").is_empty() {
+        return;
+    }
+
+    c.bench_function("parse input (real)", |b| {
+        let file = main::read_file("input");
+        b.iter(|| main::parse_input(black_box(&file)))
+    });
+
+    c.bench_function("part 1 (real)", |b| {
+        let input = main::parse_input(&main::read_file("input")).unwrap();
+        b.iter(|| main::part1(black_box(&input)))
+    });
+    
+    c.bench_function("part 2 (real)", |b| {
+        let input = main::parse_input(&main::read_file("input")).unwrap();
+        b.iter(|| main::part2(black_box(&input)))
+    });
+}
+
+criterion_group!(benches, bench_main);
+criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,79 @@
+// `main.rs` doubles as the binary of this day, which prints the answers of
+// both parts, and as the module holding the challenge logic.
+#![allow(special_module_name)]
+
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+
+mod main;
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(feature = "embedded-input")]
+pub const INPUT: Option<&str> = {
+    let input = include_str!("../input");
+    if input.is_empty() { None } else { Some(input) }
+};
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(not(feature = "embedded-input"))]
+pub const INPUT: Option<&str> = None;
+
+/// Day 19: Not Enough Minerals
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Not Enough Minerals";
+    const INPUT: Option<&'static str> = INPUT;
+
+    type Parsed = main::ParsedInput;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        main::parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> impl Display {
+        main::part1(input)
+    }
+
+    fn part2(input: &Self::Parsed) -> impl Display {
+        main::part2(input)
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<String, ParseError> {
+    Ok(Day19::part1(&Day19::parse(input)?).to_string())
+}
+
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    Ok(Day19::part2(&Day19::parse(input)?).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::main;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(main::part1(&main::parse_input(SAMPLE).unwrap()).to_string(), "33");
+    }
+
+    #[test]
+    fn part2_sample() {
+        // The sample has only two blueprints, with 56 and 62 geodes
+        assert_eq!(main::part2(&main::parse_input(SAMPLE).unwrap()).to_string(), "3472");
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = main::parse_input("Blueprint 1: Each ore robot costs 4 ore.\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = main::parse_input(&SAMPLE.replace("and 14 clay", "and 99999999999 clay")).unwrap_err();
+        assert_eq!(error.expected, "a 32-bit number");
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt::Display;
+
+use regex::Regex;
+
+use aoc_common::{parse, ParseError};
+
+// ========================= Challenge Logic ============================
+// Define your own output type here for the `parse_input` function.
+
+/// Resources, and the robots that collect them, in the order they are used as
+/// indices.
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Blueprint {
+    pub id: u32,
+    /// Resources each robot costs, indexed by robot and then by resource.
+    costs: [[u32; 4]; 4],
+    /// Robots worth building of every kind: more than the most of a resource
+    /// any robot costs is never spent, as only one robot is built at a time.
+    caps: [u32; 4],
+}
+
+pub type ParsedInput = Vec<Blueprint>;
+
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    let re = Regex::new(concat!(
+        r"^Blueprint (\d+): Each ore robot costs (\d+) ore\. Each clay robot costs (\d+) ore\. ",
+        r"Each obsidian robot costs (\d+) ore and (\d+) clay\. Each geode robot costs (\d+) ore and (\d+) obsidian\.$",
+    )).unwrap();
+    input.lines()
+    .map(|l| {
+        let caps = re.captures(l)
+            .ok_or_else(|| ParseError::at(input, l, "`Blueprint <id>: Each ore robot costs <n> ore. ...`"))?;
+        let number = |i| parse::from_str::<u32>(input, caps.get(i).unwrap().as_str(), "a 32-bit number");
+
+        let mut costs = [[0; 4]; 4];
+        costs[ORE][ORE] = number(2)?;
+        costs[CLAY][ORE] = number(3)?;
+        costs[OBSIDIAN][ORE] = number(4)?;
+        costs[OBSIDIAN][CLAY] = number(5)?;
+        costs[GEODE][ORE] = number(6)?;
+        costs[GEODE][OBSIDIAN] = number(7)?;
+
+        let mut caps = [u32::MAX; 4];
+        for resource in [ORE, CLAY, OBSIDIAN] {
+            caps[resource] = costs.iter().map(|cost| cost[resource]).max().unwrap_or(0);
+        }
+        Ok(Blueprint { id: number(1)?, costs, caps })
+    })
+    .collect()
+}
+
+/// Robots and resources at some point, with `minutes` left.
+#[derive(Clone, Copy, Debug)]
+struct State {
+    minutes: u32,
+    robots: [u32; 4],
+    stock: [u32; 4],
+}
+
+impl Blueprint {
+    /// Most geodes that can be opened in `minutes`, starting with a single ore
+    /// robot.
+    pub fn max_geodes(&self, minutes: u32) -> u32 {
+        let mut best = 0;
+        self.search(State { minutes, robots: [1, 0, 0, 0], stock: [0; 4] }, &mut best);
+        best
+    }
+
+    /// Branch and bound over the robot to build next, skipping the minutes of
+    /// waiting for its resources in between.
+    fn search(&self, state: State, best: &mut u32) {
+        let State { minutes, robots, stock } = state;
+
+        // Building nothing more still opens these geodes
+        let idle = stock[GEODE] + robots[GEODE] * minutes;
+        *best = (*best).max(idle);
+
+        // Even building a geode robot every minute from now on does not beat
+        // the best so far
+        if idle + minutes * minutes.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+
+        for robot in (0..4).rev() {
+            if robots[robot] >= self.caps[robot] {
+                continue;
+            }
+
+            // Minutes until the resources for the robot are collected, if the
+            // robots collecting them exist
+            let wait = (0..4).try_fold(0, |wait, resource| {
+                let missing = self.costs[robot][resource].saturating_sub(stock[resource]);
+                match (missing, robots[resource]) {
+                    (0, _) => Some(wait),
+                    (_, 0) => None,
+                    (missing, collecting) => Some(wait.max(missing.div_ceil(collecting))),
+                }
+            });
+
+            // A robot finished in the last minute collects nothing
+            let Some(wait) = wait.filter(|wait| wait + 1 < minutes) else { continue };
+            let mut next = State { minutes: minutes - wait - 1, robots, stock };
+            for resource in 0..4 {
+                next.stock[resource] = stock[resource] + robots[resource] * (wait + 1) - self.costs[robot][resource];
+            }
+            next.robots[robot] += 1;
+            self.search(next, best);
+        }
+    }
+}
+
+/// Most geodes of every blueprint in `minutes`, evaluating the blueprints in
+/// parallel.
+pub fn max_geodes(blueprints: &[Blueprint], minutes: u32) -> Vec<u32> {
+    std::thread::scope(|s| {
+        let handles = blueprints.iter()
+            .map(|blueprint| s.spawn(move || blueprint.max_geodes(minutes)))
+            .collect::<Vec<_>>();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+pub fn part1(input: &ParsedInput) -> impl Display {
+    input.iter()
+    .zip(max_geodes(input, 24))
+    .map(|(blueprint, geodes)| blueprint.id * geodes)
+    .sum::<u32>()
+}
+
+pub fn part2(input: &ParsedInput) -> impl Display {
+    let first = &input[..input.len().min(3)];
+    max_geodes(first, 32).into_iter().product::<u32>()
+}
+
+// =========================== Main Function ============================
+#[allow(dead_code)]
+fn main() {
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
+
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
+    formatted_print("1", part1(&input));
+    formatted_print("2", part2(&input));
+}
+
+pub fn read_file(file_name: &str) -> String {
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
+}
+
+fn formatted_print<T : Display>(part: &str, output: T) {
+    println!("==================== Part {} ======================", part);
+    println!("{}", output);
+    println!("==================================================");
+}
@@ -1,6 +1,8 @@
 use std::fmt::Display;
 use std::collections::HashMap;
 
+use aoc_common::{parse, ParseError};
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
 #[derive(Copy, Clone, Debug)]
@@ -12,52 +14,41 @@ pub enum Yell {
     Div(u32, u32),
 }
 
-type ParsedInput = HashMap<u32, Yell>;
+pub type ParsedInput = HashMap<u32, Yell>;
 
 #[inline(always)]
 pub fn slice_to_identifier(slice: &[u8]) -> u32 {
     u32::from_be_bytes(slice.try_into().expect("Could not convert slice to identifier!"))
 }
 
-pub fn parse_input(input: &str) -> ParsedInput {
+pub fn parse_input(input: &str) -> std::result::Result<ParsedInput, ParseError> {
+    // Monkey names consist of exactly four lowercase letters
+    let name = |s: &str| {
+        if s.len() == 4 && s.bytes().all(|b| b.is_ascii_lowercase()) {
+            Ok(slice_to_identifier(s.as_bytes()))
+        } else {
+            Err(ParseError::at(input, s, "a monkey name of four lowercase letters"))
+        }
+    };
+
     input.lines()
     .map(
         |l| {
-            let bytes = l.as_bytes();
-            match bytes.get(11) {
-                Some(b'+') => (
-                    slice_to_identifier(&bytes[0..4]), 
-                    Yell::Add(
-                        slice_to_identifier(&bytes[ 6..10]), 
-                        slice_to_identifier(&bytes[13..17])
-                    )
-                ),
-                Some(b'-') => (
-                    slice_to_identifier(&bytes[0..4]), 
-                    Yell::Sub(
-                        slice_to_identifier(&bytes[ 6..10]), 
-                        slice_to_identifier(&bytes[13..17])
-                    )
-                ),
-                Some(b'*') => (
-                    slice_to_identifier(&bytes[0..4]), 
-                    Yell::Mul(
-                        slice_to_identifier(&bytes[ 6..10]), 
-                        slice_to_identifier(&bytes[13..17])
-                    )
-                ),
-                Some(b'/') => (
-                    slice_to_identifier(&bytes[0..4]), 
-                    Yell::Div(
-                        slice_to_identifier(&bytes[ 6..10]), 
-                        slice_to_identifier(&bytes[13..17])
-                    )
-                ),
-                _ => (
-                    slice_to_identifier(&bytes[0..4]),
-                    Yell::Constant(l[6..].parse::<i64>().unwrap())
-                )
-            }
+            let (monkey, job) = parse::split_once(input, l, ": ")?;
+            let yell = match job.split(' ').collect::<Vec<&str>>()[..] {
+                [left, op, right] => {
+                    let (left, right) = (name(left)?, name(right)?);
+                    match op {
+                        "+" => Yell::Add(left, right),
+                        "-" => Yell::Sub(left, right),
+                        "*" => Yell::Mul(left, right),
+                        "/" => Yell::Div(left, right),
+                        _ => return Err(ParseError::at(input, op, "an operation (`+`, `-`, `*` or `/`)")),
+                    }
+                },
+                _ => Yell::Constant(parse::from_str(input, job, "a number or an operation on two monkeys")?),
+            };
+            Ok((name(monkey)?, yell))
         }
     ).collect()
 }
@@ -158,29 +149,29 @@ pub fn part2(input: &ParsedInput) -> impl Display {
             Yell::Constant(_) => todo!(),
             Yell::Add(l, r) => {
                 match (map.get(&l), map.get(&r)) {
-                    (Some(Yell::Constant(c)), _) => { val = val - c; key = r; } ,
-                    (_, Some(Yell::Constant(c))) => { val = val - c; key = l; } ,
+                    (Some(Yell::Constant(c)), _) => { val -= c; key = r; } ,
+                    (_, Some(Yell::Constant(c))) => { val -= c; key = l; } ,
                     (_, _) => todo!(),
                 };
             },
             Yell::Sub(l, r) => {
                 match (map.get(&l), map.get(&r)) {
                     (Some(Yell::Constant(c)), _) => { val = c - val; key = r; } ,
-                    (_, Some(Yell::Constant(c))) => { val = val + c; key = l; } ,
+                    (_, Some(Yell::Constant(c))) => { val += c; key = l; } ,
                     (_, _) => todo!(),
                 };
             },
             Yell::Mul(l, r) => {
                 match (map.get(&l), map.get(&r)) {
-                    (Some(Yell::Constant(c)), _) => { val = val / c; key = r; } ,
-                    (_, Some(Yell::Constant(c))) => { val = val / c; key = l; } ,
+                    (Some(Yell::Constant(c)), _) => { val /= c; key = r; } ,
+                    (_, Some(Yell::Constant(c))) => { val /= c; key = l; } ,
                     (_, _) => todo!(),
                 };
             },
             Yell::Div(l, r) => {
                 match (map.get(&l), map.get(&r)) {
                     (Some(Yell::Constant(c)), _) => { val = c / val; key = r; } ,
-                    (_, Some(Yell::Constant(c))) => { val = val * c; key = l; } ,
+                    (_, Some(Yell::Constant(c))) => { val *= c; key = l; } ,
                     (_, _) => todo!(),
                 };
             },
@@ -194,36 +185,17 @@ pub fn part2(input: &ParsedInput) -> impl Display {
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-    let input = parse_input(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "152";
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "301";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
     formatted_print("2", part2(&input));
-
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
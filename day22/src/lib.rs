@@ -0,0 +1,107 @@
+// `main.rs` doubles as the binary of this day, which prints the answers of
+// both parts, and as the module holding the challenge logic.
+#![allow(special_module_name)]
+
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+
+mod main;
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(feature = "embedded-input")]
+pub const INPUT: Option<&str> = {
+    let input = include_str!("../input");
+    if input.is_empty() { None } else { Some(input) }
+};
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(not(feature = "embedded-input"))]
+pub const INPUT: Option<&str> = None;
+
+/// Day 22: Monkey Map
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Monkey Map";
+    const INPUT: Option<&'static str> = INPUT;
+
+    type Parsed = main::ParsedInput;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        main::parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> impl Display {
+        main::part1(input)
+    }
+
+    fn part2(input: &Self::Parsed) -> impl Display {
+        main::part2(input)
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<String, ParseError> {
+    Ok(Day22::part1(&Day22::parse(input)?).to_string())
+}
+
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    Ok(Day22::part2(&Day22::parse(input)?).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Direction;
+
+    use super::main;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(main::part1(&main::parse_input(SAMPLE).unwrap()).to_string(), "6032");
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(main::part2(&main::parse_input(SAMPLE).unwrap()).to_string(), "5031");
+    }
+
+    #[test]
+    fn cube_edges() {
+        // The net of the sample, and a staircase of faces of 2 by 2 tiles
+        let staircase = "....\n....\n  ....\n  ....\n    ....\n    ....\n\n1\n";
+        for (input, size) in [(SAMPLE, 4), (staircase, 2)] {
+            let notes = main::parse_input(input).unwrap();
+
+            // Walking over an edge and straight back ends up where it started
+            let mut edges = 0;
+            for (pos, &tile) in notes.map.iter().filter(|&(_, &t)| t != main::Tile::Void) {
+                for direction in Direction::ALL {
+                    if notes.map.get(pos + direction.offset()).is_some_and(|&t| t != main::Tile::Void) {
+                        continue;
+                    }
+                    let (over, facing) = notes.cube.wrap(pos, direction);
+                    assert_ne!(notes.map[over], main::Tile::Void);
+                    assert_eq!(notes.cube.wrap(over, facing.opposite()), (pos, direction.opposite()), "{} {:?} {:?}", pos, direction, tile);
+                    edges += 1;
+                }
+            }
+            // Every tile along the 14 edges on the outside of a net
+            assert_eq!(edges, 14 * size);
+        }
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = main::parse_input("..\n.x\n\n1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = main::parse_input("......\n\n10R1\n").unwrap_err();
+        assert_eq!(error.expected, "a map that folds into a cube");
+        let error = main::parse_input(&SAMPLE.replace("10R5", "10X5")).unwrap_err();
+        assert_eq!(error.expected, "a number of steps, `L` or `R`");
+    }
+}
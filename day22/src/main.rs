@@ -0,0 +1,257 @@
+use std::fmt::Display;
+use std::collections::HashMap;
+
+use aoc_common::{parse, Coord2, Coord3, Direction, Grid, ParseError};
+
+// ========================= Challenge Logic ============================
+// Define your own output type here for the `parse_input` function.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    /// Outside the map, where the lines of the input are padded with spaces.
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Forward(u32),
+    Left,
+    Right,
+}
+
+#[derive(Clone, Debug)]
+pub struct Notes {
+    pub map: Grid<Tile>,
+    pub path: Vec<Step>,
+    pub cube: Cube,
+}
+
+pub type ParsedInput = Notes;
+
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    let (map, path) = parse::split_once(input, input, "\n\n")?;
+
+    // Lines end after their last tile, so pad them to the widest line
+    let width = map.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+    let mut cells = Vec::with_capacity(width * map.lines().count());
+    for l in map.lines() {
+        for (i, c) in l.char_indices() {
+            cells.push(match c {
+                ' ' => Tile::Void,
+                '.' => Tile::Open,
+                '#' => Tile::Wall,
+                _ => return Err(ParseError::at(input, &l[i..], "` `, `.` or `#`")),
+            });
+        }
+        cells.extend(std::iter::repeat_n(Tile::Void, width - l.chars().count()));
+    }
+    if cells.is_empty() {
+        return Err(ParseError::at_end(input, "a map"));
+    }
+    let map = Grid::from_cells(width, cells);
+
+    let path = path.trim_end();
+    let mut steps = Vec::new();
+    let mut rest = path;
+    while let Some(c) = rest.chars().next() {
+        let (step, len) = match c {
+            'L' => (Step::Left, 1),
+            'R' => (Step::Right, 1),
+            _ => {
+                let len = rest.find(['L', 'R']).unwrap_or(rest.len());
+                (Step::Forward(parse::from_str(input, &rest[..len], "a number of steps, `L` or `R`")?), len)
+            },
+        };
+        steps.push(step);
+        rest = &rest[len..];
+    }
+
+    let cube = Cube::fold(&map).ok_or_else(|| ParseError::at_end(input, "a map that folds into a cube"))?;
+    Ok(Notes { map, path: steps, cube })
+}
+
+/// Orientation of a face of the cube: the directions its rows and columns
+/// point in, and the direction pointing out of the cube.
+#[derive(Clone, Copy, Debug)]
+struct Face {
+    right: Coord3,
+    down: Coord3,
+    normal: Coord3,
+}
+
+impl Face {
+    /// Direction on the cube of a direction on the map of this face.
+    fn towards(&self, direction: Direction) -> Coord3 {
+        match direction {
+            Direction::Up => -self.down,
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => -self.right,
+        }
+    }
+
+    /// The face next to this one on the map in `direction`, folded over the
+    /// edge between them.
+    fn fold(&self, direction: Direction) -> Face {
+        let Face { right, down, normal } = *self;
+        match direction {
+            Direction::Up => Face { right, down: normal, normal: -down },
+            Direction::Right => Face { right: -normal, down, normal: right },
+            Direction::Down => Face { right, down: -normal, normal: down },
+            Direction::Left => Face { right: normal, down, normal: -right },
+        }
+    }
+}
+
+/// The map folded into a cube, with the orientation of every face, keyed by
+/// the position of the face on the map in units of faces.
+#[derive(Clone, Debug)]
+pub struct Cube {
+    size: i32,
+    faces: HashMap<Coord2, Face>,
+}
+
+fn dot(a: Coord3, b: Coord3) -> i32 {
+    (0..3).map(|i| a[i] * b[i]).sum()
+}
+
+impl Cube {
+    /// Fold the map into a cube, working out the orientation of every face
+    /// from the ones it is attached to on the map. This works for every net
+    /// of a cube, in any rotation or reflection.
+    ///
+    /// Returns `None` if the map does not consist of six square faces that
+    /// fold into a cube.
+    pub fn fold(map: &Grid<Tile>) -> Option<Cube> {
+        let tiles = map.cells().iter().filter(|&&t| t != Tile::Void).count();
+        let size = (1..).find(|&n: &i32| (6 * n * n) as usize >= tiles)?;
+        if (6 * size * size) as usize != tiles {
+            return None;
+        }
+
+        // A face on the map is a square that is not outside the map at all
+        let on_map = |face: Coord2| {
+            let corner = face * size;
+            (0..size).all(|y| (0..size).all(|x| map.get(corner + Coord2::new(x, y)).is_some_and(|&t| t != Tile::Void)))
+        };
+
+        let first = (0..map.width() as i32 / size).map(|x| Coord2::new(x, 0)).find(|&face| on_map(face))?;
+        let mut faces = HashMap::from([(first, Face { right: Coord3::new(1, 0, 0), down: Coord3::new(0, 1, 0), normal: Coord3::new(0, 0, -1) })]);
+        let mut todo = vec![first];
+        while let Some(face) = todo.pop() {
+            for direction in Direction::ALL {
+                let next = face + direction.offset();
+                if next.0.iter().all(|&c| c >= 0) && !faces.contains_key(&next) && on_map(next) {
+                    faces.insert(next, faces[&face].fold(direction));
+                    todo.push(next);
+                }
+            }
+        }
+
+        // Faces that fold onto each other mean the map is not a net of a cube
+        let mut normals = faces.values().map(|f| f.normal).collect::<Vec<Coord3>>();
+        normals.sort();
+        normals.dedup();
+        (faces.len() == 6 && normals.len() == 6).then_some(Cube { size, faces })
+    }
+
+    /// Where walking off the face of the map at `pos` in `direction` leads to,
+    /// and the direction on the map after crossing the edge of the cube.
+    pub fn wrap(&self, pos: Coord2, direction: Direction) -> (Coord2, Direction) {
+        let n = self.size;
+        let block = pos.map(|c| c.div_euclid(n));
+        let (x, y) = (pos.x() - block.x() * n, pos.y() - block.y() * n);
+        let face = self.faces[&block];
+
+        // Position on the cube, in half tiles from the center of the cube, so
+        // the centers of the tiles are on whole numbers
+        let on_cube = face.normal * n + face.right * (2 * x - (n - 1)) + face.down * (2 * y - (n - 1));
+
+        // Over the edge, heading into the next face away from this one
+        let heading = face.towards(direction);
+        let over = on_cube + heading - face.normal;
+        let (&block, next) = self.faces.iter().find(|(_, f)| f.normal == heading).unwrap();
+        let direction = Direction::ALL.into_iter().find(|&d| next.towards(d) == -face.normal).unwrap();
+
+        let (x, y) = ((dot(over, next.right) + n - 1) / 2, (dot(over, next.down) + n - 1) / 2);
+        (block * n + Coord2::new(x, y), direction)
+    }
+}
+
+impl Notes {
+    /// Follow the path from the leftmost open tile of the top row, facing
+    /// right, letting `wrap` decide where walking off the map leads to.
+    /// Returns the password for where the path ends.
+    pub fn walk(&self, wrap: impl Fn(Coord2, Direction) -> (Coord2, Direction)) -> i64 {
+        let mut pos = self.map.position(|&t| t == Tile::Open).unwrap();
+        let mut direction = Direction::Right;
+
+        for &step in &self.path {
+            match step {
+                Step::Left => direction = direction.turn_left(),
+                Step::Right => direction = direction.turn_right(),
+                Step::Forward(steps) => for _ in 0..steps {
+                    let (next, next_direction) = match self.map.get(pos + direction.offset()) {
+                        Some(Tile::Open | Tile::Wall) => (pos + direction.offset(), direction),
+                        _ => wrap(pos, direction),
+                    };
+                    if self.map[next] == Tile::Wall {
+                        break;
+                    }
+                    (pos, direction) = (next, next_direction);
+                },
+            }
+        }
+
+        let facing = match direction {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+        1000 * (pos.y() as i64 + 1) + 4 * (pos.x() as i64 + 1) + facing
+    }
+
+    /// Where walking off the map at `pos` in `direction` leads to when the map
+    /// wraps around: the tile on the other side of the row or column.
+    pub fn wrap_flat(&self, pos: Coord2, direction: Direction) -> (Coord2, Direction) {
+        let back = direction.opposite().offset();
+        let mut pos = pos;
+        while self.map.get(pos + back).is_some_and(|&t| t != Tile::Void) {
+            pos += back;
+        }
+        (pos, direction)
+    }
+}
+
+pub fn part1(input: &ParsedInput) -> impl Display {
+    input.walk(|pos, direction| input.wrap_flat(pos, direction))
+}
+
+pub fn part2(input: &ParsedInput) -> impl Display {
+    input.walk(|pos, direction| input.cube.wrap(pos, direction))
+}
+
+// =========================== Main Function ============================
+#[allow(dead_code)]
+fn main() {
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
+
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
+    formatted_print("1", part1(&input));
+    formatted_print("2", part2(&input));
+}
+
+pub fn read_file(file_name: &str) -> String {
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
+}
+
+fn formatted_print<T : Display>(part: &str, output: T) {
+    println!("==================== Part {} ======================", part);
+    println!("{}", output);
+    println!("==================================================");
+}
@@ -0,0 +1,93 @@
+// `main.rs` doubles as the binary of this day, which prints the answers of
+// both parts, and as the module holding the challenge logic.
+#![allow(special_module_name)]
+
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+
+mod main;
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(feature = "embedded-input")]
+pub const INPUT: Option<&str> = {
+    let input = include_str!("../input");
+    if input.is_empty() { None } else { Some(input) }
+};
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(not(feature = "embedded-input"))]
+pub const INPUT: Option<&str> = None;
+
+/// Day 23: Unstable Diffusion
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Unstable Diffusion";
+    const INPUT: Option<&'static str> = INPUT;
+
+    type Parsed = main::ParsedInput;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        main::parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> impl Display {
+        main::part1(input)
+    }
+
+    fn part2(input: &Self::Parsed) -> impl Display {
+        main::part2(input)
+    }
+}
+
+/// The map round by round, for following the elves while debugging.
+pub use main::dump_frames;
+
+pub fn solve_part1(input: &str) -> Result<String, ParseError> {
+    Ok(Day23::part1(&Day23::parse(input)?).to_string())
+}
+
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    Ok(Day23::part2(&Day23::parse(input)?).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::main;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(main::part1(&main::parse_input(SAMPLE).unwrap()).to_string(), "110");
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(main::part2(&main::parse_input(SAMPLE).unwrap()).to_string(), "20");
+    }
+
+    #[test]
+    fn frames() {
+        // The smaller example of the puzzle, which settles after three rounds
+        let input = main::parse_input(".....\n..##.\n..#..\n.....\n..##.\n.....\n").unwrap();
+        let expected = [
+            "== Initial State ==\n##\n#.\n..\n##\n",
+            "== End of Round 1 ==\n##\n..\n#.\n.#\n#.\n",
+            "== End of Round 2 ==\n.##.\n#...\n...#\n....\n.#..\n",
+            "== End of Round 3 ==\n..#..\n....#\n#....\n....#\n.....\n..#..\n",
+        ];
+        assert_eq!(main::dump_frames(&input, 3), expected.join("\n"));
+        assert_eq!(main::part2(&input).to_string(), "4");
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = main::parse_input("#..\n.x.\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt::Display;
+
+use aoc_common::{Bounds, Compass, Coord2, Grid, ParseError};
+
+// ========================= Challenge Logic ============================
+// Define your own output type here for the `parse_input` function.
+pub type ParsedInput = Grid<bool>;
+
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }, "`#` or `.`")
+}
+
+/// The directions the elves consider moving in, in the order of the first
+/// round, with the positions that have to be free to move there.
+const PROPOSALS: [(Compass, [Compass; 3]); 4] = [
+    (Compass::N, [Compass::N, Compass::NE, Compass::NW]),
+    (Compass::S, [Compass::S, Compass::SE, Compass::SW]),
+    (Compass::W, [Compass::W, Compass::NW, Compass::SW]),
+    (Compass::E, [Compass::E, Compass::NE, Compass::SE]),
+];
+
+/// Free space the field grows by on every side when an elf reaches its edge:
+/// a word of columns, and as many rows.
+const MARGIN: i32 = 64;
+
+/// The elves spreading out, on a field with a bit per position that grows as
+/// they do.
+#[derive(Clone, Debug)]
+pub struct Elves {
+    /// Positions of the elves on the field.
+    elves: Vec<Coord2>,
+    /// Bit per position of the field, row by row, set where an elf is.
+    bits: Vec<u64>,
+    /// Words per row of the field.
+    words: usize,
+    height: usize,
+    /// Position on the map of the top left corner of the field.
+    origin: Coord2,
+    /// Number of elves proposing to move to every position of the field,
+    /// only ever nonzero during a round.
+    proposed: Vec<u8>,
+    round: usize,
+}
+
+impl Elves {
+    pub fn new(map: &Grid<bool>) -> Elves {
+        let elves = map.iter().filter(|&(_, &elf)| elf).map(|(pos, _)| pos).collect::<Vec<Coord2>>();
+        let mut field = Elves { elves, bits: Vec::new(), words: 0, height: 0, origin: Coord2::ZERO, proposed: Vec::new(), round: 0 };
+        field.grow();
+        field
+    }
+
+    /// Number of rounds done.
+    pub fn rounds(&self) -> usize {
+        self.round
+    }
+
+    /// Position of the bit for `pos` in the field.
+    fn index(&self, pos: Coord2) -> usize {
+        pos.y() as usize * self.words * 64 + pos.x() as usize
+    }
+
+    fn occupied(&self, pos: Coord2) -> bool {
+        let i = self.index(pos);
+        self.bits[i / 64] & 1 << (i % 64) != 0
+    }
+
+    fn toggle(&mut self, pos: Coord2) {
+        let i = self.index(pos);
+        self.bits[i / 64] ^= 1 << (i % 64);
+    }
+
+    /// Make room for the elves to spread out by `MARGIN` on every side.
+    fn grow(&mut self) {
+        let bounds = Bounds::of(self.elves.iter().map(|&elf| elf + self.origin)).unwrap_or(Bounds::new(Coord2::ZERO));
+        let origin = bounds.min - Coord2::splat(MARGIN);
+        let size = bounds.size() + Coord2::splat(2 * MARGIN);
+
+        for elf in &mut self.elves {
+            *elf += self.origin - origin;
+        }
+        self.origin = origin;
+        self.words = (size.x() as usize).div_ceil(64);
+        self.height = size.y() as usize;
+        self.bits = vec![0; self.words * self.height];
+        self.proposed = vec![0; self.words * 64 * self.height];
+        for elf in self.elves.clone() {
+            self.toggle(elf);
+        }
+    }
+
+    /// Where `elf` proposes to move to this round, if anywhere.
+    fn proposal(&self, elf: Coord2) -> Option<Coord2> {
+        if Compass::ALL.iter().all(|c| !self.occupied(elf + c.offset())) {
+            return None;
+        }
+        (0..4)
+        .map(|i| &PROPOSALS[(self.round + i) % 4])
+        .find(|(_, free)| free.iter().all(|c| !self.occupied(elf + c.offset())))
+        .map(|(direction, _)| elf + direction.offset())
+    }
+
+    /// Do a round of proposing and moving, returning whether any elf moved.
+    pub fn step(&mut self) -> bool {
+        // Elves next to the edge could look or move off the field
+        let (width, height) = (self.words as i32 * 64, self.height as i32);
+        if self.elves.iter().any(|e| e.x() == 0 || e.y() == 0 || e.x() == width - 1 || e.y() == height - 1) {
+            self.grow();
+        }
+
+        let proposals = self.elves.iter().map(|&elf| self.proposal(elf)).collect::<Vec<Option<Coord2>>>();
+        for &to in proposals.iter().flatten() {
+            let i = self.index(to);
+            self.proposed[i] = self.proposed[i].saturating_add(1);
+        }
+
+        // Elves only move where no other elf proposed to move to as well
+        let mut moved = false;
+        for (e, to) in proposals.into_iter().enumerate() {
+            let Some(to) = to else { continue };
+            let i = self.index(to);
+            if self.proposed[i] == 1 {
+                self.toggle(self.elves[e]);
+                self.toggle(to);
+                self.elves[e] = to;
+                moved = true;
+            }
+        }
+        for &elf in &self.elves {
+            // Every proposed position that was not moved to still has an elf
+            // next to it, which is cleared here along with the rest
+            for c in Compass::ALL {
+                let i = self.index(elf + c.offset());
+                self.proposed[i] = 0;
+            }
+            let i = self.index(elf);
+            self.proposed[i] = 0;
+        }
+
+        self.round += 1;
+        moved
+    }
+
+    /// Smallest rectangle on the map containing all elves.
+    pub fn bounds(&self) -> Bounds<2> {
+        Bounds::of(self.elves.iter().map(|&elf| elf + self.origin)).unwrap_or(Bounds::new(Coord2::ZERO))
+    }
+
+    /// Positions in the smallest rectangle containing all elves that have no
+    /// elf on them.
+    pub fn empty_ground(&self) -> u64 {
+        self.bounds().volume() - self.elves.len() as u64
+    }
+}
+
+/// Renders the smallest rectangle containing all elves, like the maps in the
+/// puzzle.
+impl Display for Elves {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bounds = self.bounds();
+        for y in bounds.min.y()..=bounds.max.y() {
+            for x in bounds.min.x()..=bounds.max.x() {
+                let elf = self.occupied(Coord2::new(x, y) - self.origin);
+                write!(f, "{}", if elf { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// The map after every one of the first `rounds` rounds, with a header like
+/// the ones in the puzzle, for following the elves round by round.
+pub fn dump_frames(input: &ParsedInput, rounds: usize) -> String {
+    let mut elves = Elves::new(input);
+    let mut frames = format!("== Initial State ==\n{}", elves);
+    for _ in 0..rounds {
+        elves.step();
+        frames += &format!("\n== End of Round {} ==\n{}", elves.rounds(), elves);
+    }
+    frames
+}
+
+pub fn part1(input: &ParsedInput) -> impl Display {
+    let mut elves = Elves::new(input);
+    for _ in 0..10 {
+        elves.step();
+    }
+    elves.empty_ground()
+}
+
+pub fn part2(input: &ParsedInput) -> impl Display {
+    let mut elves = Elves::new(input);
+    while elves.step() {}
+    elves.rounds()
+}
+
+// =========================== Main Function ============================
+#[allow(dead_code)]
+fn main() {
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
+
+    // `--frames N` prints the map after each of the first N rounds instead,
+    // for debugging
+    let mut args = std::env::args().skip(1);
+    if let (Some("--frames"), Some(rounds)) = (args.next().as_deref(), args.next()) {
+        let rounds = rounds.parse().unwrap_or_else(|_| panic!("Invalid number of rounds: {}", rounds));
+        print!("{}", dump_frames(&input, rounds));
+        return;
+    }
+
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
+    formatted_print("1", part1(&input));
+    formatted_print("2", part2(&input));
+}
+
+pub fn read_file(file_name: &str) -> String {
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
+}
+
+fn formatted_print<T : Display>(part: &str, output: T) {
+    println!("==================== Part {} ======================", part);
+    println!("{}", output);
+    println!("==================================================");
+}
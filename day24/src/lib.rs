@@ -0,0 +1,82 @@
+// `main.rs` doubles as the binary of this day, which prints the answers of
+// both parts, and as the module holding the challenge logic.
+#![allow(special_module_name)]
+
+use std::fmt::Display;
+
+use aoc_common::{ParseError, Solution};
+
+mod main;
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(feature = "embedded-input")]
+pub const INPUT: Option<&str> = {
+    let input = include_str!("../input");
+    if input.is_empty() { None } else { Some(input) }
+};
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled and the input is not empty.
+#[cfg(not(feature = "embedded-input"))]
+pub const INPUT: Option<&str> = None;
+
+/// Day 24: Blizzard Basin
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Blizzard Basin";
+    const INPUT: Option<&'static str> = INPUT;
+
+    type Parsed = main::ParsedInput;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        main::parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> impl Display {
+        main::part1(input)
+    }
+
+    fn part2(input: &Self::Parsed) -> impl Display {
+        main::part2(input)
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<String, ParseError> {
+    Ok(Day24::part1(&Day24::parse(input)?).to_string())
+}
+
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    Ok(Day24::part2(&Day24::parse(input)?).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::main;
+
+    const SAMPLE: &str = include_str!("../sample");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(main::part1(&main::parse_input(SAMPLE).unwrap()).to_string(), "18");
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(main::part2(&main::parse_input(SAMPLE).unwrap()).to_string(), "54");
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = main::parse_input("#.####\n#>.x.#\n####.#\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        let error = main::parse_input("#.####\n#>.#.#\n####.#\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 4, "`.`, `^`, `>`, `v` or `<`"));
+        let error = main::parse_input("#.####\n.>...#\n####.#\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 1, "`#`"));
+        let error = main::parse_input("#.#..#\n#>...#\n####.#\n").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (1, "a wall with an entrance"));
+    }
+}
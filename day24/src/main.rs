@@ -0,0 +1,154 @@
+use std::fmt::Display;
+
+use aoc_common::{search, Coord2, Direction, Grid, ParseError};
+
+// ========================= Challenge Logic ============================
+// Define your own output type here for the `parse_input` function.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cell {
+    Wall,
+    Open,
+    Blizzard(Direction),
+}
+
+/// The valley inside the walls, with the blizzards where they start.
+/// Positions are relative to the top left corner inside the walls, so the
+/// entrance and exit in the walls are just outside it.
+#[derive(Clone, Debug)]
+pub struct Valley {
+    blizzards: Grid<Option<Direction>>,
+    start: Coord2,
+    end: Coord2,
+    /// Minutes after which all blizzards are back where they started.
+    period: usize,
+}
+
+pub type ParsedInput = Valley;
+
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
+    let map = Grid::parse(input, |c| match c {
+        '#' => Some(Cell::Wall),
+        '.' => Some(Cell::Open),
+        '^' => Some(Cell::Blizzard(Direction::Up)),
+        '>' => Some(Cell::Blizzard(Direction::Right)),
+        'v' => Some(Cell::Blizzard(Direction::Down)),
+        '<' => Some(Cell::Blizzard(Direction::Left)),
+        _ => None,
+    }, "`#`, `.`, `^`, `>`, `v` or `<`")?;
+
+    // The map only consists of single byte characters, so positions on the
+    // map translate directly to offsets in the input
+    let offset = |pos: Coord2| pos.y() as usize * (map.width() + 1) + pos.x() as usize;
+    let (width, height) = (map.width() as i32 - 2, map.height() as i32 - 2);
+    if width < 1 || height < 1 {
+        return Err(ParseError::at_end(input, "a valley inside the walls"));
+    }
+
+    let mut blizzards = Grid::new(width as usize, height as usize, None);
+    for (pos, &cell) in map.iter() {
+        let inside = Coord2::new(pos.x() - 1, pos.y() - 1);
+        let opening = (0..width).contains(&inside.x()) && (inside.y() == -1 || inside.y() == height);
+        match (cell, blizzards.contains(inside)) {
+            (Cell::Blizzard(direction), true) => blizzards[inside] = Some(direction),
+            (Cell::Open, true) | (Cell::Wall, false) => (),
+            (Cell::Open, false) if opening => (),
+            (_, true) => return Err(ParseError::at_offset(input, offset(pos), "`.`, `^`, `>`, `v` or `<`")),
+            (_, false) => return Err(ParseError::at_offset(input, offset(pos), "`#`")),
+        }
+    }
+
+    // The entrance is the only opening in the top wall, the exit the only one
+    // in the bottom wall
+    let opening = |y: usize, expected| match map.row(y).iter().filter(|&&c| c == Cell::Open).count() {
+        1 => Ok(Coord2::new(map.row(y).iter().position(|&c| c == Cell::Open).unwrap() as i32 - 1, y as i32 - 1)),
+        _ => Err(ParseError::at_offset(input, offset(Coord2::new(0, y as i32)), expected)),
+    };
+    let start = opening(0, "a wall with an entrance")?;
+    let end = opening(map.height() - 1, "a wall with an exit")?;
+
+    let gcd = |mut a: usize, mut b: usize| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    let period = width as usize * height as usize / gcd(width as usize, height as usize);
+    Ok(Valley { blizzards, start, end, period })
+}
+
+impl Valley {
+    /// Whether no blizzard is at `pos` after `minutes`. Rather than moving
+    /// the blizzards, this looks at where the blizzards that could be at `pos`
+    /// started out.
+    fn free(&self, pos: Coord2, minutes: usize) -> bool {
+        if pos == self.start || pos == self.end {
+            return true;
+        }
+        if !self.blizzards.contains(pos) {
+            return false;
+        }
+
+        let (width, height) = (self.blizzards.width() as i32, self.blizzards.height() as i32);
+        let (dx, dy) = ((minutes % width as usize) as i32, (minutes % height as usize) as i32);
+        let (x, y) = (pos.x(), pos.y());
+        self.blizzards[Coord2::new((x - dx).rem_euclid(width), y)] != Some(Direction::Right)
+            && self.blizzards[Coord2::new((x + dx).rem_euclid(width), y)] != Some(Direction::Left)
+            && self.blizzards[Coord2::new(x, (y - dy).rem_euclid(height))] != Some(Direction::Down)
+            && self.blizzards[Coord2::new(x, (y + dy).rem_euclid(height))] != Some(Direction::Up)
+    }
+
+    /// Minutes it takes to get from `from` to `to`, setting off after
+    /// `minutes`, or `None` if the blizzards block every way there.
+    ///
+    /// The blizzards repeat every period, so the states of the search are the
+    /// position and the time within the period.
+    pub fn crossing(&self, from: Coord2, to: Coord2, minutes: usize) -> Option<usize> {
+        let moves = |&(pos, time): &(Coord2, usize)| {
+            let time = (time + 1) % self.period;
+            std::iter::once(pos)
+            .chain(Direction::ALL.map(|d| pos + d.offset()))
+            .filter(move |&next| self.free(next, time))
+            .map(move |next| (next, time))
+        };
+        search::bfs([(from, minutes % self.period)], moves, |&(pos, _)| pos == to).goal_cost()
+    }
+
+    /// Minutes it takes to go through all of `stops` in turn, or -1 if the
+    /// blizzards block the way.
+    pub fn trip(&self, stops: &[Coord2]) -> i64 {
+        stops.windows(2)
+        .try_fold(0, |minutes, leg| Some(minutes + self.crossing(leg[0], leg[1], minutes)?))
+        .map_or(-1, |minutes| minutes as i64)
+    }
+}
+
+pub fn part1(input: &ParsedInput) -> impl Display {
+    input.trip(&[input.start, input.end])
+}
+
+pub fn part2(input: &ParsedInput) -> impl Display {
+    input.trip(&[input.start, input.end, input.start, input.end])
+}
+
+// =========================== Main Function ============================
+#[allow(dead_code)]
+fn main() {
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
+
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
+    formatted_print("1", part1(&input));
+    formatted_print("2", part2(&input));
+}
+
+pub fn read_file(file_name: &str) -> String {
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
+}
+
+fn formatted_print<T : Display>(part: &str, output: T) {
+    println!("==================== Part {} ======================", part);
+    println!("{}", output);
+    println!("==================================================");
+}
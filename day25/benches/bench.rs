@@ -11,13 +11,13 @@ fn bench_main(c: &mut Criterion) {
     });
 
     c.bench_function("part 1 (sample)", |b| {
-        let input = main::parse_input(&main::read_file("sample"));
+        let input = main::parse_input(&main::read_file("sample")).unwrap();
         b.iter(|| main::part1(black_box(&input)))
     });
 
     c.bench_function("part 2 (sample)", |b| {
-        let input = main::parse_input(&main::read_file("sample"));
-        b.iter(|| main::part2(black_box(&input)))
+        let input = main::parse_input(&main::read_file("sample")).unwrap();
+        b.iter(|| main::part2(black_box(&input), main::STARS_NEEDED))
     });
 
     c.bench_function("parse input (real)", |b| {
@@ -26,13 +26,13 @@ fn bench_main(c: &mut Criterion) {
     });
 
     c.bench_function("part 1 (real)", |b| {
-        let input = main::parse_input(&main::read_file("input"));
+        let input = main::parse_input(&main::read_file("input")).unwrap();
         b.iter(|| main::part1(black_box(&input)))
     });
     
     c.bench_function("part 2 (real)", |b| {
-        let input = main::parse_input(&main::read_file("input"));
-        b.iter(|| main::part2(black_box(&input)))
+        let input = main::parse_input(&main::read_file("input")).unwrap();
+        b.iter(|| main::part2(black_box(&input), main::STARS_NEEDED))
     });
 }
 
@@ -1,15 +1,94 @@
+// `main.rs` doubles as the binary of this day, which prints the answers of
+// both parts, and as the module holding the challenge logic.
+#![allow(special_module_name)]
+
 use std::fmt::Display;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use aoc_common::{ParseError, Solution};
 
 mod main;
 
-pub fn part1() -> impl Display {
-    let raw_input = include_str!("../input");
-    let parsed_input = main::parse_input(raw_input);
-    main::part1(&parsed_input)
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled.
+#[cfg(feature = "embedded-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input"));
+
+/// Puzzle input compiled into the crate, if the `embedded-input` feature is
+/// enabled.
+#[cfg(not(feature = "embedded-input"))]
+pub const INPUT: Option<&str> = None;
+
+/// Day 25: Full of Hot Air
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Full of Hot Air";
+    const INPUT: Option<&'static str> = INPUT;
+
+    type Parsed = main::ParsedInput;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        main::parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> impl Display {
+        main::part1(input)
+    }
+
+    /// Summary of the stars given to [`collect_stars`].
+    fn part2(input: &Self::Parsed) -> impl Display {
+        main::part2(input, STARS.load(Ordering::Relaxed))
+    }
+}
+
+pub use main::STARS_NEEDED;
+
+/// Stars collected on the other days. This day cannot count them on its own,
+/// so whoever runs it passes them in with [`collect_stars`].
+static STARS: AtomicU32 = AtomicU32::new(0);
+
+/// Set the number of stars collected on the other days, for the summary of
+/// part 2.
+pub fn collect_stars(stars: u32) {
+    STARS.store(stars, Ordering::Relaxed);
+}
+
+pub fn solve_part1(input: &str) -> Result<String, ParseError> {
+    Ok(Day25::part1(&Day25::parse(input)?).to_string())
 }
 
-pub fn part2() -> impl Display {
-    let raw_input = include_str!("../input");
-    let parsed_input = main::parse_input(raw_input);
-    main::part2(&parsed_input)
-}
\ No newline at end of file
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    Ok(Day25::part2(&Day25::parse(input)?).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::{main, Day25};
+
+    const SAMPLE: &str = include_str!("../sample");
+    const INPUT: &str = include_str!("../input");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(main::part1(&main::parse_input(SAMPLE).unwrap()).to_string(), "2=-1=0");
+    }
+
+    #[test]
+    fn part1_input() {
+        assert_eq!(Day25::part1(&Day25::parse(INPUT).unwrap()).to_string(), "20-=0=02=-21=00-02=2");
+    }
+
+    #[test]
+    fn part2_sample() {
+        let input = main::parse_input(SAMPLE).unwrap();
+        assert_eq!(main::part2(&input, 30).to_string(), "30 of 49 stars collected, 19 to go");
+        assert_eq!(main::part2(&input, 49).to_string(), "Start the blender! All 50 stars collected");
+
+        super::collect_stars(30);
+        assert_eq!(Day25::part2(&input).to_string(), "30 of 49 stars collected, 19 to go");
+    }
+}
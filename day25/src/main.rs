@@ -2,8 +2,12 @@ use std::collections::VecDeque;
 use std::fmt::{Display, Write}; 
 use std::ops::{Deref, DerefMut};
 
+use aoc_common::ParseError;
+
 // ========================= Challenge Logic ============================
 // Define your own output type here for the `parse_input` function.
+// Digits are named after their value, as they appear in the challenge
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
 #[derive(Copy, Clone, Debug)]
 #[repr(usize)]
 pub enum SNAFUDigit {
@@ -14,9 +18,16 @@ pub enum SNAFUDigit {
     TWO = 4
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Clone, Debug)]
 pub struct SNAFU(VecDeque<SNAFUDigit>);
 
+impl Default for SNAFU {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SNAFU {
     
     pub fn new() -> SNAFU {
@@ -118,13 +129,11 @@ impl Display for SNAFUDigit {
 
 impl Display for SNAFU {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.iter().fold(Ok(()), |r, d| {
-            r.and_then(|_| write!(f, "{}", d))
-        })
+        self.iter().try_for_each(|d| write!(f, "{}", d))
     }
 }
 
-type ParsedInput = Vec<SNAFU>;
+pub type ParsedInput = Vec<SNAFU>;
 
 const LUT: [[i64; 5]; 27] = {
     let mut i = 0;
@@ -133,9 +142,9 @@ const LUT: [[i64; 5]; 27] = {
     
     while i < 27 {
         _lut[i][SNAFUDigit::MIN_TWO as usize] = -2 * pow_5;
-        _lut[i][SNAFUDigit::MIN_ONE as usize] = -1 * pow_5;
-        _lut[i][SNAFUDigit::ZERO as usize] = 0 * pow_5;
-        _lut[i][SNAFUDigit::ONE as usize] = 1 * pow_5;
+        _lut[i][SNAFUDigit::MIN_ONE as usize] = -pow_5;
+        _lut[i][SNAFUDigit::ZERO as usize] = 0;
+        _lut[i][SNAFUDigit::ONE as usize] = pow_5;
         _lut[i][SNAFUDigit::TWO as usize] = 2 * pow_5;
         
         i += 1;
@@ -145,19 +154,18 @@ const LUT: [[i64; 5]; 27] = {
     _lut
 };
 
-pub fn parse_input(input: &str) -> ParsedInput {
+pub fn parse_input(input: &str) -> Result<ParsedInput, ParseError> {
     input.lines()
     .map(|l|
-        l.as_bytes()
-        .into_iter()
-        .map(|c| 
+        l.char_indices()
+        .map(|(i, c)| 
             match c {
-                &b'=' => SNAFUDigit::MIN_TWO,
-                &b'-' => SNAFUDigit::MIN_ONE,
-                &b'0' => SNAFUDigit::ZERO,
-                &b'1' => SNAFUDigit::ONE,
-                &b'2' => SNAFUDigit::TWO,
-                _ => panic!("Invalid character {}!", c),
+                '=' => Ok(SNAFUDigit::MIN_TWO),
+                '-' => Ok(SNAFUDigit::MIN_ONE),
+                '0' => Ok(SNAFUDigit::ZERO),
+                '1' => Ok(SNAFUDigit::ONE),
+                '2' => Ok(SNAFUDigit::TWO),
+                _ => Err(ParseError::at(input, &l[i..], "a SNAFU digit (`=`, `-`, `0`, `1` or `2`)")),
             }
         ).collect()
     )
@@ -165,50 +173,42 @@ pub fn parse_input(input: &str) -> ParsedInput {
 }
 
 pub fn part1(input: &ParsedInput) -> impl Display {
-    SNAFU::from(input.into_iter()
-    .map(|number| 
-        i64::from(number)
+    SNAFU::from(input.iter()
+    .map(i64::from
     )
     .sum::<i64>())
 }
 
-pub fn part2(input: &ParsedInput) -> impl Display {
-    format!("Part2 not implemented!")
+/// Stars it takes to start the blender: both parts of the first 24 days, and
+/// the first part of this one.
+pub const STARS_NEEDED: u32 = 49;
+
+/// There is no second puzzle on the last day: its star is awarded for having
+/// collected all others. Summarize how far along that is, given the number of
+/// `stars` collected.
+pub fn part2(_input: &ParsedInput, stars: u32) -> impl Display {
+    match STARS_NEEDED.checked_sub(stars) {
+        Some(0) | None => "Start the blender! All 50 stars collected".to_string(),
+        Some(missing) => format!("{} of {} stars collected, {} to go", stars, STARS_NEEDED, missing),
+    }
 }
 
 // =========================== Main Function ============================
 #[allow(dead_code)]
 fn main() {
-    // Parse sample and challenge input
-    let sample = parse_input(&read_file("sample"));
-    let input = parse_input(&read_file("input"));
-
-    // Part 1
-    // Define sample answer
-    let sample_answer_part_1 = "2=-1=0";
+    // Parse challenge input
+    let input = parse_input(&read_file("input")).unwrap_or_else(|e| panic!("Invalid input: {}", e));
 
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part1(&sample)), sample_answer_part_1);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
+    // Print output of both parts with challenge input. The sample answers are
+    // checked by the tests in `lib.rs`.
     formatted_print("1", part1(&input));
-
-    // Part 2
-    // Define sample answer
-    let sample_answer_part_2 = "";
-
-    // Evaluate sample input, and compare with sample answer
-    assert_eq!(format!("{}", part2(&sample)), sample_answer_part_2);
-
-    // If sample input evaluated correctly, print output of part 1 with 
-    // challenge output.
-    formatted_print("2", part2(&input));
-
+    // On its own this day knows of no stars of the other days, the runner
+    // counts them (`cargo run -- run 25` in the root of the repository)
+    formatted_print("2", part2(&input, 0));
 }
 
 pub fn read_file(file_name: &str) -> String {
-    return std::fs::read_to_string(file_name).expect(format!("File {} not found", file_name).as_str());
+    std::fs::read_to_string(file_name).unwrap_or_else(|_| panic!("File {} not found", file_name))
 }
 
 fn formatted_print<T : Display>(part: &str, output: T) {
@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use aoc_common::Part;
+use serde::{Deserialize, Serialize};
+
+/// Default location of the answer database, relative to the working directory.
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+/// Database of confirmed answers, keyed by day, part and a hash of the input
+/// they were computed for. Stored as TOML, so changes show up nicely in diffs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part, String), String>,
+}
+
+/// Outcome of checking an answer against the database.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches the confirmed answer.
+    Pass,
+    /// The answer differs from the confirmed answer, which is included.
+    Fail(String),
+    /// There is no confirmed answer for this day, part and input.
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail(_) => write!(f, "fail"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// On-disk representation of the database.
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct AnswersFile {
+    #[serde(default, rename = "answer")]
+    answers: Vec<AnswerEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct AnswerEntry {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: String,
+}
+
+/// Hash identifying a puzzle input in the database.
+///
+/// This is a 64-bit FNV-1a hash of the input without trailing whitespace, so
+/// it does not matter whether an editor added a final newline. It only has to
+/// be stable between builds, not cryptographically secure.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.trim_end().bytes()
+        .fold(0xcbf29ce484222325_u64, |hash, b| (hash ^ b as u64).wrapping_mul(0x100000001b3));
+    format!("{:016x}", hash)
+}
+
+impl Answers {
+    /// Load the database at `path`. A missing file is an empty database.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(e) => return Err(format!("could not read {}: {}", path.display(), e)),
+        };
+
+        let file: AnswersFile = toml::from_str(&contents)
+            .map_err(|e| format!("invalid answer database {}: {}", path.display(), e))?;
+
+        let mut answers = Answers::default();
+        for entry in file.answers {
+            let part = match entry.part {
+                1 => Part::One,
+                2 => Part::Two,
+                p => return Err(format!("invalid part {} in answer database {}", p, path.display())),
+            };
+            answers.answers.insert((entry.day, part, entry.input), entry.answer);
+        }
+
+        Ok(answers)
+    }
+
+    /// Write the database to `path`, sorted by day, part and input.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let file = AnswersFile {
+            answers: self.answers.iter()
+                .map(|((day, part, input), answer)| AnswerEntry {
+                    day: *day,
+                    part: part.number(),
+                    input: input.clone(),
+                    answer: answer.clone(),
+                })
+                .collect(),
+        };
+
+        let contents = toml::to_string(&file)
+            .map_err(|e| format!("could not serialize answer database: {}", e))?;
+        std::fs::write(path, contents)
+            .map_err(|e| format!("could not write {}: {}", path.display(), e))
+    }
+
+    /// The confirmed answer for `part` of `day` with the input hashing to `hash`.
+    pub fn get(&self, day: u8, part: Part, hash: &str) -> Option<&str> {
+        self.answers.get(&(day, part, hash.to_owned())).map(String::as_str)
+    }
+
+    /// Record `answer` as the confirmed answer, replacing any previous one.
+    pub fn insert(&mut self, day: u8, part: Part, hash: &str, answer: &str) {
+        self.answers.insert((day, part, hash.to_owned()), answer.to_owned());
+    }
+
+    /// Check `answer` against the confirmed answer.
+    pub fn check(&self, day: u8, part: Part, hash: &str, answer: &str) -> Verdict {
+        match self.get(day, part, hash) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_owned()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_hash_ignores_trailing_whitespace() {
+        assert_eq!(input_hash("1\n2\n3"), input_hash("1\n2\n3\n"));
+        assert_ne!(input_hash("1\n2\n3"), input_hash("1\n2\n4"));
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+    }
+
+    #[test]
+    fn check_answers() {
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, "abc", "24000");
+
+        assert_eq!(answers.check(1, Part::One, "abc", "24000"), Verdict::Pass);
+        assert_eq!(answers.check(1, Part::One, "abc", "45000"), Verdict::Fail(String::from("24000")));
+        assert_eq!(answers.check(1, Part::Two, "abc", "45000"), Verdict::Unknown);
+        assert_eq!(answers.check(1, Part::One, "def", "24000"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc2022-answers-{}.toml", std::process::id()));
+
+        let mut answers = Answers::default();
+        answers.insert(10, Part::Two, "abc", "██  ██\n███   \n");
+        answers.insert(1, Part::One, "abc", "24000");
+        answers.save(&path).unwrap();
+
+        let loaded = Answers::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, Ok(answers));
+    }
+
+    #[test]
+    fn missing_file_is_empty() {
+        let path = Path::new("this/file/does/not/exist.toml");
+        assert_eq!(Answers::load(path), Ok(Answers::default()));
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// Default location of the benchmark baseline, relative to the working
+/// directory.
+pub const DEFAULT_PATH: &str = "bench-baseline.json";
+
+/// Default fraction a benchmark may slow down before it counts as a regression.
+pub const DEFAULT_THRESHOLD: f64 = 0.10;
+
+/// Median time in nanoseconds of every benchmark, keyed by benchmark id (e.g.
+/// `day04/parse`). Stored as JSON.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Baseline {
+    pub benchmarks: BTreeMap<String, f64>,
+}
+
+impl Baseline {
+    /// Load the baseline at `path`. A missing file is an empty baseline.
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(e) => return Err(format!("could not read {}: {}", path.display(), e)),
+        };
+
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("invalid baseline {}: {}", path.display(), e))
+    }
+
+    /// Write the baseline to `path`.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("could not serialize baseline: {}", e))?;
+        std::fs::write(path, contents + "\n")
+            .map_err(|e| format!("could not write {}: {}", path.display(), e))
+    }
+
+    /// Compare the benchmarks in `current` against this baseline. Benchmarks
+    /// that got slower by more than `threshold` (a fraction) are regressions.
+    pub fn compare(&self, current: &Baseline, threshold: f64) -> Report {
+        let changes = current.benchmarks.iter()
+            .map(|(id, &time)| Change {
+                id: id.clone(),
+                baseline: self.benchmarks.get(id).copied(),
+                current: time,
+                regression: self.benchmarks.get(id).is_some_and(|&base| time > base * (1.0 + threshold)),
+            })
+            .collect();
+
+        Report { threshold, changes }
+    }
+}
+
+/// Change of a single benchmark compared to the baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub id: String,
+    /// Time in the baseline, `None` for new benchmarks.
+    pub baseline: Option<f64>,
+    pub current: f64,
+    pub regression: bool,
+}
+
+impl Change {
+    /// Relative change compared to the baseline, e.g. `0.25` for 25% slower.
+    pub fn relative(&self) -> Option<f64> {
+        self.baseline.map(|base| self.current / base - 1.0)
+    }
+}
+
+/// Comparison of benchmark results against a baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub threshold: f64,
+    pub changes: Vec<Change>,
+}
+
+impl Report {
+    pub fn regressions(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|c| c.regression)
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let time = |ns: f64| format!("{:.2?}", std::time::Duration::from_nanos(ns as u64));
+
+        writeln!(f, "{:<14} {:>12} {:>12} {:>9}", "Benchmark", "Baseline", "Current", "Change")?;
+        for c in &self.changes {
+            let baseline = c.baseline.map_or(String::from("-"), time);
+            let change = c.relative().map_or(String::from("new"), |r| format!("{:+.1}%", r * 100.0));
+            let flag = if c.regression { "  REGRESSION" } else { "" };
+            writeln!(f, "{:<14} {:>12} {:>12} {:>9}{}", c.id, baseline, time(c.current), change, flag)?;
+        }
+
+        let regressions = self.regressions().count();
+        write!(f, "{} regression(s) beyond {:.0}%", regressions, self.threshold * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baseline(benchmarks: &[(&str, f64)]) -> Baseline {
+        Baseline { benchmarks: benchmarks.iter().map(|&(id, t)| (id.to_owned(), t)).collect() }
+    }
+
+    #[test]
+    fn flags_regressions() {
+        let base = baseline(&[("day01/parse", 100.0), ("day01/part1", 100.0), ("day01/part2", 100.0)]);
+        let current = baseline(&[("day01/parse", 109.0), ("day01/part1", 150.0), ("day01/part2", 50.0), ("day02/parse", 10.0)]);
+
+        let report = base.compare(&current, 0.10);
+        let regressions = report.regressions().map(|c| c.id.as_str()).collect::<Vec<&str>>();
+        assert_eq!(regressions, ["day01/part1"]);
+
+        let new = report.changes.iter().find(|c| c.id == "day02/parse").unwrap();
+        assert_eq!((new.baseline, new.relative(), new.regression), (None, None, false));
+        assert!(report.to_string().ends_with("1 regression(s) beyond 10%"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc2022-baseline-{}.json", std::process::id()));
+
+        let base = baseline(&[("day01/parse", 1234.5), ("day10/part2", 42.0)]);
+        base.save(&path).unwrap();
+
+        let loaded = Baseline::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, Ok(base));
+    }
+}
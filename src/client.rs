@@ -0,0 +1,247 @@
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_common::Part;
+
+use crate::config::{Config, YEAR};
+use crate::submissions::{self, Outcome};
+
+/// Longest `Retry-After` the client is willing to wait for.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Identifies the runner to the server, as asked for by Advent of Code.
+const USER_AGENT: &str = "aoc2022 runner (https://github.com/Kortekaasy/aoc2022)";
+
+/// Client for an Advent of Code compatible server.
+///
+/// Responses that do not change are cached per year and day, and requests are
+/// spaced out by at least the configured interval, also between runs, as the
+/// time of the last request is kept in the cache directory.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    /// The session token, or why there is none. Only requests need it, so
+    /// without one the cached responses can still be used.
+    session: Result<String, String>,
+    cache_dir: PathBuf,
+    request_interval: Duration,
+}
+
+/// Response of the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Client, String> {
+        Ok(Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: config.base_url().to_owned(),
+            session: config.session().map(str::to_owned),
+            cache_dir: config.cache_dir()?,
+            request_interval: config.request_interval(),
+        })
+    }
+
+    /// Directory the responses for `day` are cached in.
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(YEAR.to_string()).join(format!("day{:02}", day))
+    }
+
+    /// The puzzle input of `day`, from the cache unless `refresh` is set.
+    pub fn input(&self, day: u8, refresh: bool) -> Result<String, String> {
+        self.cached(day, "input", refresh, || {
+            let response = self.get(&format!("/{}/day/{}/input", YEAR, day))?;
+            match response.status {
+                200 => Ok(response.body),
+                404 => Err(format!("the input of day {} is not available (yet)", day)),
+                400 | 401 | 500 => Err(format!("the server rejected the session token: {}", response.body.trim())),
+                status => Err(format!("unexpected response {} to fetching the input of day {}", status, day)),
+            }
+        })
+    }
+
+    /// The first example of the puzzle description of `day`, from the cache
+    /// unless `refresh` is set.
+    pub fn sample(&self, day: u8, refresh: bool) -> Result<String, String> {
+        self.cached(day, "sample", refresh, || {
+            let response = self.get(&format!("/{}/day/{}", YEAR, day))?;
+            match response.status {
+                200 => extract_sample(&response.body)
+                    .ok_or_else(|| format!("could not find an example in the description of day {}", day)),
+                404 => Err(format!("the description of day {} is not available (yet)", day)),
+                status => Err(format!("unexpected response {} to fetching the description of day {}", status, day)),
+            }
+        })
+    }
+
+    /// Submit `answer` for `part` of `day`, and return how the server judged
+    /// it.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Outcome, String> {
+        let level = part.number().to_string();
+        let response = self.post(&format!("/{}/day/{}/answer", YEAR, day), &[("level", &level), ("answer", answer)])?;
+        match response.status {
+            200 => submissions::parse_response(&response.body)
+                .ok_or_else(|| format!("could not make sense of the response to submitting challenge {}.{}", day, part)),
+            404 => Err(format!("day {} is not open for answers (yet)", day)),
+            400 | 401 | 500 => Err(format!("the server rejected the session token: {}", response.body.trim())),
+            status => Err(format!("unexpected response {} to submitting challenge {}.{}", status, day, part)),
+        }
+    }
+
+    /// Read `name` of `day` from the cache, or store the result of `fetch` in
+    /// it when it is not there or `refresh` is set.
+    fn cached(&self, day: u8, name: &str, refresh: bool, fetch: impl FnOnce() -> Result<String, String>) -> Result<String, String> {
+        let path = self.day_dir(day).join(name);
+        if !refresh {
+            if let Ok(contents) = std::fs::read_to_string(&path) {
+                return Ok(contents);
+            }
+        }
+
+        let contents = fetch()?;
+        std::fs::create_dir_all(self.day_dir(day))
+            .and_then(|()| std::fs::write(&path, &contents))
+            .map_err(|e| format!("could not cache {}: {}", path.display(), e))?;
+        Ok(contents)
+    }
+
+    /// Send a GET request for `path` on the server.
+    pub fn get(&self, path: &str) -> Result<Response, String> {
+        self.send(path, None)
+    }
+
+    /// Send a POST request with the form `form` for `path` on the server.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        self.send(path, Some(form))
+    }
+
+    /// Send a request, waiting for the request interval first, and once more
+    /// for as long as the server asks when it responds with 429 Too Many
+    /// Requests.
+    fn send(&self, path: &str, form: Option<&[(&str, &str)]>) -> Result<Response, String> {
+        let session = self.session.as_deref().map_err(String::clone)?;
+        let url = format!("{}{}", self.base_url, path);
+        let mut retried = false;
+        loop {
+            self.throttle();
+
+            let request = match form {
+                Some(_) => self.agent.post(&url),
+                None => self.agent.get(&url),
+            }
+            .set("Cookie", &format!("session={}", session));
+
+            let result = match form {
+                Some(form) => request.send_form(form),
+                None => request.call(),
+            };
+            let response = match result {
+                Ok(response) => response,
+                Err(ureq::Error::Status(_, response)) => response,
+                Err(e) => return Err(format!("request to {} failed: {}", url, e)),
+            };
+
+            let status = response.status();
+            let retry_after = response.header("Retry-After")
+                .and_then(|secs| secs.trim().parse::<u64>().ok())
+                .map(Duration::from_secs);
+
+            match (status, retry_after) {
+                (429, Some(wait)) if !retried && wait <= MAX_RETRY_AFTER => {
+                    std::thread::sleep(wait);
+                    retried = true;
+                },
+                (429, _) => return Err(format!("the server is rate limiting requests to {}, try again later", url)),
+                _ => {
+                    let body = response.into_string()
+                        .map_err(|e| format!("could not read the response from {}: {}", url, e))?;
+                    return Ok(Response { status, body });
+                },
+            }
+        }
+    }
+
+    /// Wait until the request interval has passed since the last request, and
+    /// note the time of this request.
+    fn throttle(&self) {
+        let path = self.cache_dir.join("last-request");
+        let now = || SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+
+        let last = std::fs::read_to_string(&path).ok()
+            .and_then(|millis| millis.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+        if let Some(wait) = last.and_then(|last| (last + self.request_interval).checked_sub(now())) {
+            std::thread::sleep(wait.min(self.request_interval));
+        }
+
+        // Failing to note the time only affects the next run
+        let _ = std::fs::create_dir_all(&self.cache_dir)
+            .and_then(|()| std::fs::write(&path, now().as_millis().to_string()));
+    }
+}
+
+/// Extract the first example from the HTML of a puzzle description: the first
+/// `<pre><code>` block after a mention of "example", or else the first block.
+pub fn extract_sample(html: &str) -> Option<String> {
+    const START: &str = "<pre><code>";
+    const END: &str = "</code></pre>";
+
+    let blocks = html.match_indices(START).map(|(i, _)| i).collect::<Vec<usize>>();
+    let block = blocks.iter().enumerate()
+        .find(|&(n, &i)| {
+            // Look for the mention between the previous block and this one
+            let from = if n == 0 { 0 } else { blocks[n - 1] };
+            html[from..i].to_lowercase().contains("example")
+        })
+        .or(blocks.iter().enumerate().next())
+        .map(|(_, &i)| i + START.len())?;
+
+    let code = &html[block..block + html[block..].find(END)?];
+    Some(unescape(&strip_tags(code)))
+}
+
+/// Remove HTML tags like `<em>` from `html`.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text
+}
+
+/// Replace the HTML entities that show up in puzzle descriptions.
+fn unescape(html: &str) -> String {
+    html.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_from_description() {
+        let html = concat!(
+            "<article><p>The map looks like this:</p>\n<pre><code>not this</code></pre>\n",
+            "<p>For example, suppose you have the following list:</p>\n",
+            "<pre><code>[1,1,3,1,1]\n<em>[1,1,5,1,1]</em>\n&lt;&amp;&gt;\n</code></pre>\n",
+            "<p>Another example:</p><pre><code>second</code></pre></article>",
+        );
+
+        assert_eq!(extract_sample(html).as_deref(), Some("[1,1,3,1,1]\n[1,1,5,1,1]\n<&>\n"));
+        assert_eq!(extract_sample("<pre><code>only\n</code></pre>").as_deref(), Some("only\n"));
+        assert_eq!(extract_sample("<p>no code</p>"), None);
+    }
+}
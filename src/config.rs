@@ -0,0 +1,119 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Year of the Advent of Code these are the solutions of.
+pub const YEAR: u16 = 2022;
+
+/// Server to fetch inputs from and submit answers to, unless configured
+/// otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Minimum time between two requests to the server, unless configured
+/// otherwise.
+pub const DEFAULT_REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Configuration of the runner for talking to the Advent of Code server.
+///
+/// Read from `aoc2022/config.toml` in the configuration directory of the user
+/// (`~/.config` on Linux), or the file in `AOC_CONFIG`. The environment
+/// variables `AOC_SESSION`, `AOC_BASE_URL` and `AOC_CACHE_DIR` take precedence
+/// over the file.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in user.
+    pub session: Option<String>,
+    /// Base URL of the server, without a trailing slash.
+    pub base_url: Option<String>,
+    /// Directory to cache inputs in, instead of the per-user cache directory.
+    pub cache_dir: Option<PathBuf>,
+    /// Minimum time between two requests in seconds.
+    pub request_interval: Option<f64>,
+}
+
+impl Config {
+    /// Location of the configuration file.
+    pub fn path() -> Option<PathBuf> {
+        std::env::var_os("AOC_CONFIG").map(PathBuf::from)
+            .or_else(|| dirs::config_dir().map(|dir| dir.join("aoc2022").join("config.toml")))
+    }
+
+    /// Load the configuration from the configuration file and the environment.
+    pub fn load() -> Result<Config, String> {
+        Config::load_from(Config::path().as_deref(), |key| std::env::var(key).ok())
+    }
+
+    /// Load the configuration from the file at `path`, which may be missing,
+    /// overriding it with the variables returned by `env`.
+    pub fn load_from(path: Option<&Path>, env: impl Fn(&str) -> Option<String>) -> Result<Config, String> {
+        let mut config = match path.map(std::fs::read_to_string) {
+            Some(Ok(contents)) => toml::from_str(&contents)
+                .map_err(|e| format!("invalid configuration {}: {}", path.unwrap().display(), e))?,
+            Some(Err(e)) if e.kind() != std::io::ErrorKind::NotFound => {
+                return Err(format!("could not read {}: {}", path.unwrap().display(), e));
+            },
+            _ => Config::default(),
+        };
+
+        config.session = env("AOC_SESSION").or(config.session);
+        config.base_url = env("AOC_BASE_URL").or(config.base_url);
+        config.cache_dir = env("AOC_CACHE_DIR").map(PathBuf::from).or(config.cache_dir);
+        Ok(config)
+    }
+
+    /// The session token, which is required to get anything user specific.
+    pub fn session(&self) -> Result<&str, String> {
+        match self.session.as_deref().map(str::trim) {
+            Some(session) if !session.is_empty() => Ok(session),
+            _ => Err(format!(
+                "no session token, set AOC_SESSION or `session` in {}",
+                Config::path().map_or(String::from("the configuration file"), |p| p.display().to_string()),
+            )),
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL).trim_end_matches('/')
+    }
+
+    /// Directory to cache server responses in.
+    pub fn cache_dir(&self) -> Result<PathBuf, String> {
+        self.cache_dir.clone()
+            .or_else(|| dirs::cache_dir().map(|dir| dir.join("aoc2022")))
+            .ok_or_else(|| String::from("could not find a cache directory, set AOC_CACHE_DIR"))
+    }
+
+    pub fn request_interval(&self) -> Duration {
+        self.request_interval.map_or(DEFAULT_REQUEST_INTERVAL, |secs| Duration::from_secs_f64(secs.max(0.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn environment_overrides_file() {
+        let path = std::env::temp_dir().join(format!("aoc2022-config-{}.toml", std::process::id()));
+        std::fs::write(&path, "session = \"from-file\"\nbase_url = \"http://localhost:8080/\"\nrequest_interval = 0.5\n").unwrap();
+
+        let config = Config::load_from(Some(&path), |key| (key == "AOC_SESSION").then(|| String::from("from-env")));
+        std::fs::remove_file(&path).unwrap();
+        let config = config.unwrap();
+
+        assert_eq!(config.session(), Ok("from-env"));
+        assert_eq!(config.base_url(), "http://localhost:8080");
+        assert_eq!(config.request_interval(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn defaults() {
+        let config = Config::load_from(Some(Path::new("this/file/does/not/exist.toml")), |_| None).unwrap();
+
+        assert!(config.session().is_err());
+        assert_eq!(config.base_url(), DEFAULT_BASE_URL);
+        assert_eq!(config.request_interval(), DEFAULT_REQUEST_INTERVAL);
+    }
+}
@@ -0,0 +1,40 @@
+use std::io::Read;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where to read a puzzle input from at runtime.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// Read the input from standard input, given as `-` on the command line.
+    Stdin,
+    /// Read the input from a file.
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Read the full input from this source.
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)
+                    .map_err(|e| format!("could not read input from stdin: {}", e))?;
+                Ok(input)
+            },
+            InputSource::File(path) => std::fs::read_to_string(path)
+                .map_err(|e| format!("could not read input from {}: {}", path.display(), e)),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(String::from("expected a path, or `-` for stdin")),
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::File(PathBuf::from(path))),
+        }
+    }
+}
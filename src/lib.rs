@@ -0,0 +1,9 @@
+//! Shared parts of the Advent of Code 2022 runner, used by the `aoc2022`
+//! binary, the workspace benchmarks and the tests.
+
+pub mod answers;
+pub mod baseline;
+pub mod client;
+pub mod config;
+pub mod registry;
+pub mod submissions;
@@ -1,90 +1,466 @@
-use std::fmt::Display;
-
-use day01;
-use day02;
-use day03;
-use day04;
-use day05;
-use day06;
-use day07;
-use day08;
-use day09;
-use day10;
-use day11;
-use day12;
-use day13;
-use day14;
-use day15;
-// use day16;
-// use day17;
-// use day18;
-// use day19;
-use day20;
-use day21;
-// use day22;
-// use day23;
-// use day24;
-// use day25;
-
-fn main() {
-    run_challenge(" 1.1", day01::part1);
-    run_challenge(" 1.2", day01::part2);
-    run_challenge(" 2.1", day02::part1);
-    run_challenge(" 2.2", day02::part2);
-    run_challenge(" 3.1", day03::part1);
-    run_challenge(" 3.2", day03::part2);
-    run_challenge(" 4.1", day04::part1);
-    run_challenge(" 4.2", day04::part2);
-    run_challenge(" 5.1", day05::part1);
-    run_challenge(" 5.2", day05::part2);
-    run_challenge(" 6.1", day06::part1);
-    run_challenge(" 6.2", day06::part2);
-    run_challenge(" 7.1", day07::part1);
-    run_challenge(" 7.2", day07::part2);
-    run_challenge(" 8.1", day08::part1);
-    run_challenge(" 8.2", day08::part2);
-    run_challenge(" 9.1", day09::part1);
-    run_challenge(" 9.2", day09::part2);
-    run_challenge("10.1", day10::part1);
-    run_challenge("10.2", day10::part2);
-    run_challenge("11.1", day11::part1);
-    run_challenge("11.2", day11::part2);
-    run_challenge("12.1", day12::part1);
-    run_challenge("12.2", day12::part2);
-    run_challenge("13.1", day13::part1);
-    run_challenge("13.2", day13::part2);
-    run_challenge("14.1", day14::part1);
-    run_challenge("14.2", day14::part2);
-    run_challenge("15.1", day15::part1);
-    run_challenge("15.2", day15::part2);
-//     run_challenge("16.1", day16::part1);
-//     run_challenge("16.2", day16::part2);
-//     run_challenge("17.1", day17::part1);
-//     run_challenge("17.2", day17::part2);
-//     run_challenge("18.1", day18::part1);
-//     run_challenge("18.2", day18::part2);
-//    run_challenge("19.1", day19::part1);
-//    run_challenge("19.2", day19::part2);
-   run_challenge("20.1", day20::part1);
-   run_challenge("20.2", day20::part2);
-   run_challenge("21.1", day21::part1);
-   run_challenge("21.2", day21::part2);
-//    run_challenge("22.1", day22::part1);
-//    run_challenge("22.2", day22::part2);
-//    run_challenge("23.1", day23::part1);
-//    run_challenge("23.2", day23::part2);
-//    run_challenge("24.1", day24::part1);
-//    run_challenge("24.2", day24::part2);
-//    run_challenge("25.1", day25::part1);
-//    run_challenge("25.2", day25::part2);
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc2022::answers::{self, Answers, Verdict};
+use aoc2022::client::Client;
+use aoc2022::config::Config;
+use aoc2022::registry::{self, DAYS};
+use aoc2022::submissions::{self, History};
+use aoc_common::{DynSolution, ParseError, Part};
+use clap::{Args, Parser, Subcommand};
+
+mod input;
+mod output;
+mod parallel;
+mod scaffold;
+mod selection;
+mod timing;
+use input::InputSource;
+use output::{Format, Output, Record, Status};
+use selection::Selection;
+use timing::Timing;
+
+/// Runner for the Advent of Code 2022 challenges.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run the solutions of one or more challenges
+    Run(RunArgs),
+    /// Create the crate for a new day, and register it with the runner
+    New(NewArgs),
+    /// Download the input and example of days into their crates
+    Fetch(FetchArgs),
+    /// Solve a challenge and submit the answer
+    Submit(SubmitArgs),
+}
+
+#[derive(Args, Debug)]
+struct NewArgs {
+    /// Day of the advent calendar
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Title of the challenge
+    #[arg(long)]
+    title: Option<String>,
+}
+
+#[derive(Args, Debug)]
+struct FetchArgs {
+    /// Days to fetch
+    #[arg(required = true, value_parser = clap::value_parser!(u8).range(1..=25))]
+    days: Vec<u8>,
+
+    /// Download again, instead of using the cached copies
+    #[arg(long)]
+    refresh: bool,
+
+    /// Overwrite inputs and examples in the day crates that differ
+    #[arg(long)]
+    force: bool,
+
+    /// Only fetch the input, not the example
+    #[arg(long)]
+    no_sample: bool,
+}
+
+#[derive(Args, Debug)]
+struct SubmitArgs {
+    /// Day of the advent calendar
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Part of the challenge
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Submit this answer, instead of solving the challenge
+    #[arg(long)]
+    answer: Option<String>,
+
+    /// Read the puzzle input from a file, or from stdin when given `-`,
+    /// instead of using the input compiled into the binary
+    #[arg(long, value_name = "FILE")]
+    input: Option<InputSource>,
+
+    /// Location of the answer database, where correct answers are recorded
+    #[arg(long, value_name = "FILE", default_value = answers::DEFAULT_PATH)]
+    answers: PathBuf,
+
+    /// Location of the history of submitted answers
+    #[arg(long, value_name = "FILE", default_value = submissions::DEFAULT_PATH)]
+    history: PathBuf,
+}
+
+#[derive(Args, Debug, Default)]
+struct RunArgs {
+    /// Challenges to run: a day (`14`), a part of a day (`13.2`), or a range
+    /// of days (`1..=10`, `1..11`)
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    challenges: Vec<Selection>,
+
+    /// Run all challenges
+    #[arg(long)]
+    all: bool,
+
+    /// Read the puzzle input from a file, or from stdin when given `-`,
+    /// instead of using the input compiled into the binary. Only valid when a
+    /// single day is selected.
+    #[arg(long, value_name = "FILE")]
+    input: Option<InputSource>,
+
+    /// Check the answers against the answer database, and fail on mismatches
+    #[arg(long)]
+    verify: bool,
+
+    /// Like `--verify`, but also add answers that are not in the database yet
+    #[arg(long)]
+    record: bool,
+
+    /// Location of the answer database
+    #[arg(long, value_name = "FILE", default_value = answers::DEFAULT_PATH)]
+    answers: PathBuf,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Parse and solve every part this many times, reporting the minimum and
+    /// median time
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+
+    /// Number of days to solve at the same time
+    #[arg(short, long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: u32,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    // Without a subcommand, run everything
+    let result = match cli.command {
+        Some(Command::Run(args)) => run(args),
+        Some(Command::New(args)) => new(args).map(|()| true),
+        Some(Command::Fetch(args)) => fetch(args),
+        Some(Command::Submit(args)) => submit(args),
+        None => run(RunArgs { all: true, answers: PathBuf::from(answers::DEFAULT_PATH), repeat: 1, jobs: 1, ..Default::default() }),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+/// Create the crate of a new day in the current directory.
+fn new(args: NewArgs) -> Result<(), String> {
+    let title = args.title.unwrap_or_else(|| format!("Day {}", args.day));
+    scaffold::new_day(Path::new("."), args.day, &title)?;
+
+    println!("Created day{:02}, put the puzzle input in day{:02}/input and the example in day{:02}/sample", args.day, args.day, args.day);
+    Ok(())
+}
+
+/// Fetch the inputs and examples of days, and put them in the day crates in the
+/// current directory when they exist.
+fn fetch(args: FetchArgs) -> Result<bool, String> {
+    let client = Client::new(&Config::load()?)?;
+
+    let mut success = true;
+    for day in args.days {
+        let mut files = vec![("input", client.input(day, args.refresh))];
+        if !args.no_sample {
+            files.push(("sample", client.sample(day, args.refresh)));
+        }
+
+        let dir = PathBuf::from(format!("day{:02}", day));
+        for (name, contents) in files {
+            let contents = match contents {
+                Ok(contents) => contents,
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    success = false;
+                    continue;
+                },
+            };
+
+            let path = dir.join(name);
+            if !dir.exists() {
+                println!("Fetched the {} of day {} into {}, create the crate with `new {}`", name, day, client.day_dir(day).display(), day);
+                continue;
+            }
+
+            // Leave files alone that were filled in by hand, unless forced
+            match std::fs::read_to_string(&path) {
+                Ok(existing) if existing == contents => println!("{} is up to date", path.display()),
+                Ok(existing) if !existing.is_empty() && !args.force => {
+                    eprintln!("error: {} differs from the fetched {}, pass --force to overwrite it", path.display(), name);
+                    success = false;
+                },
+                _ => {
+                    std::fs::write(&path, contents).map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+                    println!("Wrote {}", path.display());
+                },
+            }
+        }
+    }
+
+    Ok(success)
+}
+
+/// Solve a challenge and submit the answer, unless it is known to be wrong.
+/// Returns whether the answer is correct.
+fn submit(args: SubmitArgs) -> Result<bool, String> {
+    let (day, part) = (args.day, Part::ALL[args.part as usize - 1]);
+    if (day, part) == registry::SUMMARY {
+        return Err(String::from("day 25 has no second puzzle, its star is awarded for collecting all others"));
+    }
+    let d = registry::get(day).ok_or_else(|| format!("no solution for day {}", day))?;
+
+    let input = match args.input {
+        Some(source) => source.read()?,
+        None => d.input().ok_or_else(|| format!("no input for day {}, pass one with --input", day))?.to_owned(),
+    };
+    let hash = answers::input_hash(&input);
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => match d.parse_input(&input) {
+            Ok(parsed) => d.solve(part, parsed.as_ref()),
+            Err(error) => {
+                print_parse_error(day, &input, &error);
+                return Ok(false);
+            },
+        },
+    };
+    if answer.contains('\n') {
+        return Err(format!("the answer of challenge {}.{} spans multiple lines, read it and pass it with --answer:\n{}", day, part, answer));
+    }
+
+    // Never bother the server with answers that are known to be wrong
+    let mut history = History::load(&args.history)?;
+    match history.accepted(day, part, &hash) {
+        Some(accepted) if accepted == answer => {
+            println!("Challenge {}.{} was already answered with `{}`", day, part, answer);
+            return Ok(true);
+        },
+        Some(accepted) => {
+            eprintln!("error: challenge {}.{} was already answered with `{}`, not submitting `{}`", day, part, accepted, answer);
+            return Ok(false);
+        },
+        None => (),
+    }
+    if let Some(reason) = history.known_wrong(day, part, &hash, &answer) {
+        eprintln!("error: not submitting the answer to challenge {}.{}: {}", day, part, reason);
+        return Ok(false);
+    }
+
+    let client = Client::new(&Config::load()?)?;
+    println!("Submitting `{}` for challenge {}.{}", answer, day, part);
+    let outcome = client.submit(day, part, &answer)?;
+    println!("Challenge {}.{}: {}", day, part, outcome);
+
+    history.insert(day, part, &hash, &answer, outcome);
+    history.save(&args.history)?;
+
+    if outcome == submissions::Outcome::Correct {
+        let mut answers = Answers::load(&args.answers)?;
+        answers.insert(day, part, &hash, &answer);
+        answers.save(&args.answers)?;
+    }
+
+    Ok(outcome == submissions::Outcome::Correct)
+}
+
+/// Run the selected challenges. Returns whether all of them succeeded.
+fn run(args: RunArgs) -> Result<bool, String> {
+    let selections = if args.all { Vec::new() } else { args.challenges };
+    let selected = |day: u8, part: Part| selections.is_empty() || selections.iter().any(|s| s.contains(day, part.number()));
+    let explicit = |day: u8| selections.iter().any(|s| s.is_single_day() && s.days.contains(&day));
+
+    // Days that were asked for explicitly, but have no solution (yet)
+    let missing = selections.iter()
+        .filter(|s| s.is_single_day())
+        .filter(|s| !DAYS.iter().any(|d| Part::ALL.iter().any(|part| s.contains(d.day(), part.number()))))
+        .collect::<Vec<&Selection>>();
+
+    for s in &missing {
+        eprintln!("error: no solution for challenge {}", s);
+    }
+
+    let days = DAYS.iter()
+        .filter(|d| Part::ALL.into_iter().any(|part| selected(d.day(), part)))
+        .collect::<Vec<&&dyn DynSolution>>();
+
+    // A runtime input belongs to a single day, so only allow it for one
+    let input = match args.input {
+        Some(_) if days.len() != 1 => return Err(String::from("--input requires exactly one day to be selected")),
+        Some(source) => Some(source.read()?),
+        None => None,
+    };
+
+    // Only touch the answer database when asked to, or to count the stars of
+    // all registered days for the summary
+    let verify = args.verify || args.record;
+    let summarize = selected(registry::SUMMARY.0, registry::SUMMARY.1);
+    let mut answers = if verify || summarize { Answers::load(&args.answers)? } else { Answers::default() };
+    let mut verdicts: Vec<Verdict> = Vec::new();
+    if summarize {
+        registry::collect_stars(&answers);
+    }
+
+    let repeat = args.repeat as usize;
+    let mut output = Output::new(args.format, repeat);
+    let mut success = missing.is_empty();
+
+    // Solve the days on the worker threads, but verify and print the results
+    // here, in order of the days
+    let run_day = |d: &&&dyn DynSolution| {
+        let parts = Part::ALL.into_iter().filter(|&part| selected(d.day(), part)).collect();
+        solve_day(**d, input.as_deref(), parts, repeat)
+    };
+    parallel::map_ordered(&days, args.jobs as usize, run_day, |run| {
+        let record = |part: Part, status| Record {
+            day: run.day,
+            part: part.number(),
+            answer: None,
+            parse_time: None,
+            solve_time: None,
+            status,
+        };
+
+        let (hash, parse_time, solved) = match run.outcome {
+            // Days without an input yet are skipped when running a range of
+            // days, and only fail when asked for by name
+            Outcome::NoInput if !explicit(run.day) => {
+                eprintln!("note: skipping day {}, it has no input", run.day);
+                run.parts.iter().for_each(|&part| output.record(record(part, Status::NoInput)));
+                return;
+            },
+            Outcome::NoInput => {
+                eprintln!("error: no input for day {}, pass one with --input", run.day);
+                run.parts.iter().for_each(|&part| output.record(record(part, Status::NoInput)));
+                success = false;
+                return;
+            },
+            Outcome::InvalidInput { input, error, parse_time } => {
+                print_parse_error(run.day, input, &error);
+                run.parts.iter().for_each(|&part| output.record(Record { parse_time: Some(parse_time), ..record(part, Status::InvalidInput) }));
+                success = false;
+                return;
+            },
+            Outcome::Solved { hash, parse_time, solved } => (hash, parse_time, solved),
+        };
+
+        for (part, answer, solve_time) in solved {
+            let status = if (run.day, part) == registry::SUMMARY {
+                Status::Summary
+            } else if verify {
+                let verdict = answers.check(run.day, part, &hash, &answer);
+                match &verdict {
+                    Verdict::Pass => (),
+                    Verdict::Fail(expected) => {
+                        eprintln!("error: challenge {}.{} answered `{}`, but expected `{}`", run.day, part, answer, expected);
+                        success = false;
+                    },
+                    Verdict::Unknown if args.record => answers.insert(run.day, part, &hash, &answer),
+                    Verdict::Unknown => (),
+                }
+                let status = Status::from_verdict(&verdict);
+                verdicts.push(verdict);
+                status
+            } else {
+                Status::Ok
+            };
+
+            output.record(Record {
+                answer: Some(answer),
+                parse_time: Some(parse_time),
+                solve_time: Some(solve_time),
+                ..record(part, status)
+            });
+        }
+    });
+
+    let summary = verify.then(|| {
+        let count = |f: fn(&Verdict) -> bool| verdicts.iter().filter(|v| f(v)).count();
+        format!(
+            "Verified {} answers: {} passed, {} failed, {} unknown{}",
+            verdicts.len(),
+            count(|v| *v == Verdict::Pass),
+            count(|v| matches!(v, Verdict::Fail(_))),
+            count(|v| *v == Verdict::Unknown),
+            if args.record { " (now recorded)" } else { "" },
+        )
+    });
+    output.finish(summary.as_deref())?;
+
+    if args.record {
+        answers.save(&args.answers)?;
+    }
+
+    Ok(success)
+}
+
+/// Selected parts of a day, and the outcome of running them.
+struct DayRun<'a> {
+    day: u8,
+    parts: Vec<Part>,
+    outcome: Outcome<'a>,
+}
+
+enum Outcome<'a> {
+    /// There is no input for the day.
+    NoInput,
+    /// The input could not be parsed.
+    InvalidInput { input: &'a str, error: ParseError, parse_time: Timing },
+    /// The parts were solved, giving the answer and time of each part.
+    Solved { hash: String, parse_time: Timing, solved: Vec<(Part, String, Timing)> },
+}
+
+/// Parse the input of `d` and solve `parts`, each `repeat` times. `input`
+/// overrides the input compiled into the binary.
+fn solve_day<'a>(d: &dyn DynSolution, input: Option<&'a str>, parts: Vec<Part>, repeat: usize) -> DayRun<'a> {
+    // Prefer the input given at runtime, fall back on the compiled-in input
+    let Some(input) = input.or(d.input()) else {
+        return DayRun { day: d.day(), parts, outcome: Outcome::NoInput };
+    };
+
+    let (parsed, parse_time) = timing::measure(repeat, || d.parse_input(input));
+    let outcome = match parsed {
+        Ok(parsed) => Outcome::Solved {
+            hash: answers::input_hash(input),
+            parse_time,
+            solved: parts.iter()
+                .map(|&part| {
+                    let (answer, solve_time) = timing::measure(repeat, || d.solve(part, parsed.as_ref()));
+                    (part, answer, solve_time)
+                })
+                .collect(),
+        },
+        Err(error) => Outcome::InvalidInput { input, error, parse_time },
+    };
+
+    DayRun { day: d.day(), parts, outcome }
 }
 
-fn run_challenge<F, O>(title: &str, func: F) 
-    where F: Fn() -> O, O : Display {
-    println!("================= Challenge {} =================", title);
-    let string_output = format!("{}", func());
-    for l in string_output.lines() {
-        println!("| {:^46} |", l);
+/// Print where parsing the input of `day` failed, quoting the offending line.
+fn print_parse_error(day: u8, input: &str, error: &ParseError) {
+    eprintln!("error: invalid input for day {}: {}", day, error);
+    if let Some(line) = input.lines().nth(error.line - 1) {
+        let gutter = error.line.to_string().len();
+        eprintln!("{:>w$} | {}", error.line, line, w = gutter);
+        eprintln!("{:>w$} | {:>c$}", "", "^", w = gutter, c = error.column);
     }
-    println!("==================================================");
 }
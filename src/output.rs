@@ -0,0 +1,353 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use aoc2022::answers::Verdict;
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::timing::Timing;
+
+/// How the runner prints the answers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Every answer in a box, for reading in a terminal
+    #[default]
+    Box,
+    /// One answer per line, with multi-line answers on the lines below
+    Plain,
+    /// A JSON array of records
+    Json,
+    /// CSV with a header row, one record per line
+    Csv,
+}
+
+/// Outcome of running one part of a challenge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Status {
+    /// The part was solved, without checking the answer.
+    Ok,
+    /// The answer matches the answer database.
+    Pass,
+    /// The answer differs from the answer database.
+    Fail,
+    /// The answer database has no answer for this part and input.
+    Unknown,
+    /// The input could not be parsed.
+    InvalidInput,
+    /// There was no input to run the part on.
+    NoInput,
+    /// The answer sums up the other answers rather than solving a puzzle, so
+    /// it is never verified.
+    Summary,
+}
+
+impl Status {
+    pub fn from_verdict(verdict: &Verdict) -> Status {
+        match verdict {
+            Verdict::Pass => Status::Pass,
+            Verdict::Fail(_) => Status::Fail,
+            Verdict::Unknown => Status::Unknown,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Unknown => "unknown",
+            Status::InvalidInput => "invalid-input",
+            Status::NoInput => "no-input",
+            Status::Summary => "summary",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Result of running one part of a challenge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// The answer, `None` when the part could not be run.
+    pub answer: Option<String>,
+    /// Time spent parsing the input, shared by both parts of a day.
+    pub parse_time: Option<Timing>,
+    pub solve_time: Option<Timing>,
+    pub status: Status,
+}
+
+/// Flat representation of a [`Record`] for JSON and CSV, with the times in
+/// nanoseconds. The `_time_ns` fields hold the median.
+#[derive(Serialize)]
+struct Row<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a str>,
+    parse_time_ns: Option<u64>,
+    parse_min_ns: Option<u64>,
+    solve_time_ns: Option<u64>,
+    solve_min_ns: Option<u64>,
+    status: Status,
+}
+
+impl<'a> From<&'a Record> for Row<'a> {
+    fn from(record: &'a Record) -> Row<'a> {
+        let nanos = |t: Duration| t.as_nanos() as u64;
+        Row {
+            day: record.day,
+            part: record.part,
+            answer: record.answer.as_deref(),
+            parse_time_ns: record.parse_time.map(|t| nanos(t.median)),
+            parse_min_ns: record.parse_time.map(|t| nanos(t.min)),
+            solve_time_ns: record.solve_time.map(|t| nanos(t.median)),
+            solve_min_ns: record.solve_time.map(|t| nanos(t.min)),
+            status: record.status,
+        }
+    }
+}
+
+/// Prints records in a [`Format`]. Records are printed as they come in, except
+/// for JSON, which is printed as a whole by [`Output::finish`].
+pub struct Output {
+    format: Format,
+    /// Number of runs the times are taken over.
+    repeat: usize,
+    records: Vec<Record>,
+}
+
+impl Output {
+    /// Start the output, printing the CSV header if needed.
+    pub fn new(format: Format, repeat: usize) -> Output {
+        if format == Format::Csv {
+            println!("day,part,answer,parse_time_ns,parse_min_ns,solve_time_ns,solve_min_ns,status");
+        }
+
+        Output { format, repeat, records: Vec::new() }
+    }
+
+    pub fn record(&mut self, record: Record) {
+        match self.format {
+            Format::Box => print_box(&record),
+            Format::Plain => print_plain(&record),
+            Format::Csv => print_csv(&record),
+            Format::Json => (),
+        }
+        self.records.push(record);
+    }
+
+    /// Finish the output. The timing table and `summary` are only shown in the
+    /// box and plain formats, the other formats include both in the records.
+    pub fn finish(self, summary: Option<&str>) -> Result<(), String> {
+        match self.format {
+            Format::Box | Format::Plain => {
+                print_timings(&self.records, self.repeat);
+                if let Some(summary) = summary {
+                    println!("{}", summary);
+                }
+            },
+            Format::Json => {
+                let rows = self.records.iter().map(Row::from).collect::<Vec<Row>>();
+                let json = serde_json::to_string_pretty(&rows)
+                    .map_err(|e| format!("could not serialize records: {}", e))?;
+                println!("{}", json);
+            },
+            Format::Csv => (),
+        }
+
+        Ok(())
+    }
+}
+
+/// Print the answer in a box, with the verdict of verifying it in the bottom
+/// edge. Parts that could not be run are left out, their errors are reported
+/// separately.
+fn print_box(record: &Record) {
+    let Some(answer) = &record.answer else {
+        return;
+    };
+
+    println!("================= Challenge {:>2}.{} =================", record.day, record.part);
+    for l in answer.lines() {
+        println!("| {:^46} |", l);
+    }
+    match record.status {
+        Status::Ok => println!("=================================================="),
+        status => println!("{:=^50}", format!(" {} ", status)),
+    }
+}
+
+fn print_plain(record: &Record) {
+    let timing = |name, t: Timing| match t.min == t.median {
+        true => format!("{} {:.2?}", name, t.median),
+        false => format!("{} {:.2?} (min {:.2?})", name, t.median, t.min),
+    };
+    let details = [
+        Some(record.status.to_string()),
+        record.parse_time.map(|t| timing("parse", t)),
+        record.solve_time.map(|t| timing("solve", t)),
+    ];
+    let details = details.into_iter().flatten().collect::<Vec<String>>().join(", ");
+
+    match &record.answer {
+        Some(answer) if answer.contains('\n') => {
+            println!("day {} part {}: ({})", record.day, record.part, details);
+            println!("{}", answer.trim_end_matches('\n'));
+        },
+        Some(answer) => println!("day {} part {}: {} ({})", record.day, record.part, answer, details),
+        None => println!("day {} part {}: ({})", record.day, record.part, details),
+    }
+}
+
+fn print_csv(record: &Record) {
+    let row = Row::from(record);
+    let nanos = |t: Option<u64>| t.map_or(String::new(), |t| t.to_string());
+    println!(
+        "{},{},{},{},{},{},{},{}",
+        row.day,
+        row.part,
+        csv_field(row.answer.unwrap_or("")),
+        nanos(row.parse_time_ns),
+        nanos(row.parse_min_ns),
+        nanos(row.solve_time_ns),
+        nanos(row.solve_min_ns),
+        row.status,
+    );
+}
+
+/// Quote a CSV field if needed, as described in RFC 4180.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Median times of a single day, for the timing table.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct DayTimes {
+    day: u8,
+    parse: Option<Duration>,
+    parts: [Option<Duration>; 2],
+}
+
+impl DayTimes {
+    fn total(&self) -> Duration {
+        self.parse.into_iter().chain(self.parts.into_iter().flatten()).sum()
+    }
+}
+
+/// Collect the median times of the records per day, in order of appearance.
+/// Days that did not get to parsing are left out.
+fn day_times(records: &[Record]) -> Vec<DayTimes> {
+    let mut days: Vec<DayTimes> = Vec::new();
+    for record in records.iter().filter(|r| r.parse_time.is_some()) {
+        if days.last().is_none_or(|d| d.day != record.day) {
+            days.push(DayTimes { day: record.day, ..Default::default() });
+        }
+
+        let times = days.last_mut().unwrap();
+        times.parse = times.parse.or(record.parse_time.map(|t| t.median));
+        times.parts[record.part as usize - 1] = record.solve_time.map(|t| t.median);
+    }
+
+    days
+}
+
+/// Print a table of the parse and solve times per day, with a total, so it is
+/// easy to see which day takes the longest.
+fn print_timings(records: &[Record], repeat: usize) {
+    let days = day_times(records);
+    if days.is_empty() {
+        return;
+    }
+
+    let cell = |t: Option<Duration>| t.map_or(String::from("-"), |t| format!("{:.2?}", t));
+    let row = |day: &str, parse: String, part1: String, part2: String, total: String| {
+        println!("| {:>5} | {:>10} | {:>10} | {:>10} | {:>10} |", day, parse, part1, part2, total);
+    };
+    let line = || println!("+{}+", ["-------", "------------", "------------", "------------", "------------"].join("+"));
+
+    match repeat {
+        1 => println!("Timings:"),
+        n => println!("Timings (median of {} runs):", n),
+    }
+    line();
+    row("Day", String::from("Parse"), String::from("Part 1"), String::from("Part 2"), String::from("Total"));
+    line();
+    for d in &days {
+        row(&d.day.to_string(), cell(d.parse), cell(d.parts[0]), cell(d.parts[1]), cell(Some(d.total())));
+    }
+    line();
+
+    // Sum up the columns, skipping the parts that did not run
+    let sum = |f: fn(&DayTimes) -> Option<Duration>| cell(Some(days.iter().filter_map(f).sum()));
+    row("Total", sum(|d| d.parse), sum(|d| d.parts[0]), sum(|d| d.parts[1]), sum(|d| Some(d.total())));
+    line();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, part: u8, parse: u64, solve: Option<u64>) -> Record {
+        let timing = |ms| Timing { min: Duration::from_millis(ms), median: Duration::from_millis(ms) };
+        Record {
+            day,
+            part,
+            answer: solve.map(|_| String::from("42")),
+            parse_time: Some(timing(parse)),
+            solve_time: solve.map(timing),
+            status: Status::Ok,
+        }
+    }
+
+    #[test]
+    fn csv_quoting() {
+        assert_eq!(csv_field("12640"), "12640");
+        assert_eq!(csv_field("██ █\n█ ██\n"), "\"██ █\n█ ██\n\"");
+        assert_eq!(csv_field("a \"b\", c"), "\"a \"\"b\"\", c\"");
+    }
+
+    #[test]
+    fn json_record() {
+        let record = Record {
+            day: 10,
+            part: 2,
+            answer: Some(String::from("█ █\n █ \n")),
+            parse_time: Some(Timing { min: Duration::from_micros(10), median: Duration::from_micros(12) }),
+            solve_time: None,
+            status: Status::InvalidInput,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&Row::from(&record)).unwrap(),
+            concat!(
+                r#"{"day":10,"part":2,"answer":"█ █\n █ \n","parse_time_ns":12000,"parse_min_ns":10000,"#,
+                r#""solve_time_ns":null,"solve_min_ns":null,"status":"invalid-input"}"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn times_per_day() {
+        let no_input = Record { parse_time: None, ..record(2, 1, 0, None) };
+        let records = [record(1, 1, 5, Some(1)), record(1, 2, 5, Some(2)), no_input, record(3, 2, 7, None)];
+        let ms = |ms| Some(Duration::from_millis(ms));
+
+        let days = day_times(&records);
+        assert_eq!(days, [
+            DayTimes { day: 1, parse: ms(5), parts: [ms(1), ms(2)] },
+            DayTimes { day: 3, parse: ms(7), parts: [None, None] },
+        ]);
+        assert_eq!(days[0].total(), Duration::from_millis(8));
+        assert_eq!(days[1].total(), Duration::from_millis(7));
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+
+/// Stack size of the worker threads, the usual size for a main thread.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Apply `f` to all `items` on `jobs` threads, passing the results to `consume`
+/// on the calling thread in the order of `items`. Results are handed over as
+/// soon as all results before them are in, so output can be streamed.
+///
+/// With a single job everything runs on the calling thread.
+pub fn map_ordered<T, R>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync, mut consume: impl FnMut(R))
+where T: Sync, R: Send {
+    if jobs <= 1 {
+        items.iter().map(f).for_each(consume);
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel::<(usize, R)>();
+
+    std::thread::scope(|s| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            // Solutions may keep large arrays on the stack, so give the
+            // workers as much stack as the main thread gets
+            let worker = std::thread::Builder::new().stack_size(STACK_SIZE);
+            worker.spawn_scoped(s, move || {
+                // Keep taking the next item until all are taken
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        return;
+                    };
+                    if sender.send((i, f(item))).is_err() {
+                        return;
+                    }
+                }
+            }).expect("could not spawn worker thread");
+        }
+        drop(sender);
+
+        // Hold on to results that finished early until it is their turn
+        let mut pending: BTreeMap<usize, R> = BTreeMap::new();
+        let mut turn = 0;
+        for (i, result) in receiver {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&turn) {
+                consume(result);
+                turn += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order() {
+        let items = (0..50).collect::<Vec<u64>>();
+        for jobs in [1, 4, 100] {
+            let mut results = Vec::new();
+            // Make the early items the slowest, so they finish out of order
+            map_ordered(&items, jobs, |&i| {
+                std::thread::sleep(std::time::Duration::from_micros(50 - i));
+                i * 2
+            }, |r| results.push(r));
+            assert_eq!(results, items.iter().map(|i| i * 2).collect::<Vec<u64>>());
+        }
+    }
+}
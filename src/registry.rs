@@ -0,0 +1,63 @@
+use aoc_common::{DynSolution, Part};
+
+use crate::answers::{self, Answers};
+
+/// Solutions of all days, in order of the advent calendar.
+///
+/// This is the single list of days the runner, the benchmarks and the tests
+/// iterate over, so adding a day only requires registering it here (and
+/// adding the crate as a dependency).
+pub static DAYS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+/// Get the solution of day `day`, if there is one.
+pub fn get(day: u8) -> Option<&'static dyn DynSolution> {
+    DAYS.iter().copied().find(|d| d.day() == day)
+}
+
+/// The part whose answer sums up the others instead of solving a puzzle: the
+/// second part of the last day, as its star is awarded for collecting all
+/// others. Its answer changes with every star, so it is never verified.
+pub const SUMMARY: (u8, Part) = (25, Part::Two);
+
+/// Number of stars collected: the parts of the registered days with an answer
+/// in `answers` for their compiled in input, not counting [`SUMMARY`].
+pub fn stars(answers: &Answers) -> u32 {
+    DAYS.iter()
+    .filter_map(|day| Some((day.day(), answers::input_hash(day.input()?))))
+    .flat_map(|(day, hash)| Part::ALL.map(|part| (day, part, hash.clone())))
+    .filter(|&(day, part, _)| (day, part) != SUMMARY)
+    .filter(|(day, part, hash)| answers.get(*day, *part, hash).is_some())
+    .count() as u32
+}
+
+/// Count the [`stars`] in `answers`, and tell the last day about them for its
+/// summary.
+pub fn collect_stars(answers: &Answers) {
+    day25::collect_stars(stars(answers));
+}
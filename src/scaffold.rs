@@ -0,0 +1,177 @@
+use std::path::Path;
+
+/// Files of a new day crate, relative to its directory, and their templates.
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/day/Cargo.toml.tmpl")),
+    (".gitignore", include_str!("../templates/day/gitignore.tmpl")),
+    ("src/lib.rs", include_str!("../templates/day/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/day/main.rs.tmpl")),
+    ("benches/bench.rs", include_str!("../templates/day/bench.rs.tmpl")),
+    ("sample", ""),
+    ("input", ""),
+];
+
+/// Create the crate of `day` in the repository at `root`, and register it with
+/// the runner. When any of the steps fails, the crate is removed again and the
+/// manifest and registry are restored, so nothing is left half done.
+pub fn new_day(root: &Path, day: u8, title: &str) -> Result<(), String> {
+    let dir = root.join(format!("day{:02}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let read = |path: &Path| std::fs::read_to_string(path)
+        .map_err(|e| format!("could not read {}: {} (run this from the root of the repository)", path.display(), e));
+    let write = |path: &Path, contents: &str| std::fs::write(path, contents)
+        .map_err(|e| format!("could not write {}: {}", path.display(), e));
+
+    // Work out all edits before writing anything
+    let manifest_path = root.join("Cargo.toml");
+    let registry_path = root.join("src").join("registry.rs");
+    let (old_manifest, old_registry) = (read(&manifest_path)?, read(&registry_path)?);
+    let manifest = add_to_manifest(&old_manifest, day)?;
+    let registry = add_to_registry(&old_registry, day)?;
+
+    let created = (|| {
+        for (file, template) in TEMPLATES {
+            let path = dir.join(file);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| format!("could not create {}: {}", parent.display(), e))?;
+            }
+            write(&path, &render(template, day, title))?;
+        }
+        write(&manifest_path, &manifest)?;
+        write(&registry_path, &registry)
+    })();
+
+    // Undo whatever was written before the failure, the error is what matters
+    if created.is_err() {
+        let _ = std::fs::remove_dir_all(&dir);
+        let _ = std::fs::write(&manifest_path, &old_manifest);
+        let _ = std::fs::write(&registry_path, &old_registry);
+    }
+    created
+}
+
+/// Fill in the placeholders of a template.
+fn render(template: &str, day: u8, title: &str) -> String {
+    template
+        .replace("{{day}}", &format!("{:02}", day))
+        .replace("{{number}}", &day.to_string())
+        .replace("{{title}}", &title.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Add the crate of `day` as a dependency of the root manifest, and forward its
+/// `embedded-input` feature.
+fn add_to_manifest(manifest: &str, day: u8) -> Result<String, String> {
+    let manifest = insert_day_line(manifest, day, "day", "#", &format!("day{:02} = {{ path = \"day{:02}\", default-features = false }}", day, day))?;
+    insert_day_line(&manifest, day, "\"day", "#", &format!("\"day{:02}/embedded-input\",", day))
+}
+
+/// Add the solution of `day` to the registry.
+fn add_to_registry(registry: &str, day: u8) -> Result<String, String> {
+    insert_day_line(registry, day, "&day", "//", &format!("&day{:02}::Day{:02},", day, day))
+}
+
+/// The day a line is about, and whether it is commented out, if the line
+/// (after the comment marker `comment`) starts with `prefix` followed by the
+/// two digits of a day.
+fn day_of_line(line: &str, prefix: &str, comment: &str) -> Option<(u8, bool)> {
+    let line = line.trim_start();
+    let (line, commented) = match line.strip_prefix(comment) {
+        Some(rest) => (rest.trim_start(), true),
+        None => (line, false),
+    };
+
+    let digits = line.strip_prefix(prefix)?.get(..2)?;
+    match digits.bytes().all(|b| b.is_ascii_digit()) {
+        true => Some((digits.parse().ok()?, commented)),
+        false => None,
+    }
+}
+
+/// Insert `line` into the list of lines about days in `text`, keeping the list
+/// sorted by day. A commented out line for `day` is replaced.
+fn insert_day_line(text: &str, day: u8, prefix: &str, comment: &str, line: &str) -> Result<String, String> {
+    let mut lines = text.lines().collect::<Vec<&str>>();
+    let days = lines.iter().enumerate()
+        .filter_map(|(i, l)| day_of_line(l, prefix, comment).map(|(d, commented)| (i, d, commented)))
+        .collect::<Vec<(usize, u8, bool)>>();
+
+    let Some(&(first, _, _)) = days.first() else {
+        return Err(format!("could not find where to add `{}`", line));
+    };
+    let indent = &lines[first][..lines[first].len() - lines[first].trim_start().len()];
+    let line = format!("{}{}", indent, line);
+
+    match days.iter().find(|&&(_, d, _)| d >= day) {
+        Some(&(_, d, false)) if d == day => return Err(format!("day {} is already added: `{}`", day, line.trim())),
+        Some(&(i, d, true)) if d == day => lines[i] = &line,
+        Some(&(i, _, _)) => lines.insert(i, &line),
+        None => lines.insert(days.last().unwrap().0 + 1, &line),
+    }
+
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest() {
+        let manifest = concat!(
+            "[dependencies]\n",
+            "day01 = { path = \"day01\", default-features = false }\n",
+            "#day02 = { path = \"day02\", default-features = false }\n",
+            "day05 = { path = \"day05\", default-features = false }\n",
+            "\n",
+            "[features]\n",
+            "embedded-input = [\n",
+            "    \"day01/embedded-input\",\n",
+            "    \"day05/embedded-input\",\n",
+            "]\n",
+        );
+
+        assert_eq!(add_to_manifest(manifest, 2).unwrap(), concat!(
+            "[dependencies]\n",
+            "day01 = { path = \"day01\", default-features = false }\n",
+            "day02 = { path = \"day02\", default-features = false }\n",
+            "day05 = { path = \"day05\", default-features = false }\n",
+            "\n",
+            "[features]\n",
+            "embedded-input = [\n",
+            "    \"day01/embedded-input\",\n",
+            "    \"day02/embedded-input\",\n",
+            "    \"day05/embedded-input\",\n",
+            "]\n",
+        ));
+        assert!(add_to_manifest(manifest, 5).is_err());
+    }
+
+    #[test]
+    fn registry() {
+        let registry = "pub static DAYS: &[&dyn DynSolution] = &[\n    &day01::Day01,\n    // &day02::Day02,\n];\n";
+
+        assert_eq!(
+            add_to_registry(registry, 2).unwrap(),
+            "pub static DAYS: &[&dyn DynSolution] = &[\n    &day01::Day01,\n    &day02::Day02,\n];\n",
+        );
+        assert_eq!(
+            add_to_registry(registry, 7).unwrap(),
+            "pub static DAYS: &[&dyn DynSolution] = &[\n    &day01::Day01,\n    // &day02::Day02,\n    &day07::Day07,\n];\n",
+        );
+    }
+
+    #[test]
+    fn templates() {
+        let lib = render(TEMPLATES[2].1, 7, "No Space \"Left\"");
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(lib.contains("const DAY: u8 = 7;"));
+        assert!(lib.contains("const TITLE: &'static str = \"No Space \\\"Left\\\"\";"));
+        assert!(!lib.contains("{{"));
+    }
+}
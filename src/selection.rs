@@ -0,0 +1,132 @@
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// A selection of challenges given on the command line.
+///
+/// Accepted forms are a single day (`14`), a single part of a day (`13.2`),
+/// and a range of days, either exclusive (`1..10`) or inclusive (`1..=10`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Selection {
+    pub days: RangeInclusive<u8>,
+    pub part: Option<u8>,
+}
+
+impl Selection {
+    /// Whether part `part` of day `day` is part of this selection.
+    pub fn contains(&self, day: u8, part: u8) -> bool {
+        self.days.contains(&day) && self.part.is_none_or(|p| p == part)
+    }
+
+    /// Whether this selection names exactly one day, rather than a range.
+    pub fn is_single_day(&self) -> bool {
+        self.days.start() == self.days.end()
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day `{}`, expected a number between 1 and 25", s)),
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Ranges of days, e.g. `1..=10` or `1..10`
+        if let Some((start, end)) = s.split_once("..") {
+            let start = parse_day(start)?;
+            let end = match end.strip_prefix('=') {
+                Some(end) => parse_day(end)?,
+                // The end of an exclusive range may be one past the last day
+                None => match end.parse::<u8>() {
+                    Ok(end) if (2..=26).contains(&end) => end - 1,
+                    _ => return Err(format!("invalid end of range `{}`", s)),
+                },
+            };
+
+            if end < start {
+                return Err(format!("empty range `{}`", s));
+            }
+
+            return Ok(Selection { days: start..=end, part: None });
+        }
+
+        // Single part of a day, e.g. `13.2`
+        if let Some((day, part)) = s.split_once('.') {
+            let day = parse_day(day)?;
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(format!("invalid part `{}`, expected 1 or 2", part)),
+            };
+
+            return Ok(Selection { days: day..=day, part: Some(part) });
+        }
+
+        // Single day, e.g. `14`
+        let day = parse_day(s)?;
+        Ok(Selection { days: day..=day, part: None })
+    }
+}
+
+impl Display for Selection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.is_single_day(), self.part) {
+            (true, Some(part)) => write!(f, "{}.{}", self.days.start(), part),
+            (true, None) => write!(f, "{}", self.days.start()),
+            (false, _) => write!(f, "{}..={}", self.days.start(), self.days.end()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Selection, String> {
+        s.parse()
+    }
+
+    #[test]
+    fn days_and_parts() {
+        assert_eq!(parse("14"), Ok(Selection { days: 14..=14, part: None }));
+        assert_eq!(parse("13.2"), Ok(Selection { days: 13..=13, part: Some(2) }));
+        assert!(parse("0").is_err());
+        assert!(parse("26").is_err());
+        assert!(parse("13.3").is_err());
+        assert!(parse("13.").is_err());
+        assert!(parse("x").is_err());
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(parse("1..=10"), Ok(Selection { days: 1..=10, part: None }));
+        // The end of an exclusive range is one past the last day
+        assert_eq!(parse("1..11"), Ok(Selection { days: 1..=10, part: None }));
+        assert_eq!(parse("2..26"), Ok(Selection { days: 2..=25, part: None }));
+        assert!(parse("2..=26").is_err());
+        assert!(parse("2..27").is_err());
+
+        // Empty ranges
+        assert!(parse("5..5").is_err());
+        assert!(parse("5..=4").is_err());
+        assert!(parse("1..1").is_err());
+        assert!(parse("..5").is_err());
+    }
+
+    #[test]
+    fn contains_and_display() {
+        let range = parse("3..6").unwrap();
+        assert!(range.contains(3, 1) && range.contains(5, 2));
+        assert!(!range.contains(6, 1) && !range.is_single_day());
+        assert_eq!(range.to_string(), "3..=5");
+
+        let part = parse("13.2").unwrap();
+        assert!(part.contains(13, 2) && !part.contains(13, 1) && part.is_single_day());
+        assert_eq!(part.to_string(), "13.2");
+        assert_eq!(parse("14").unwrap().to_string(), "14");
+    }
+}
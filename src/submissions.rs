@@ -0,0 +1,291 @@
+use std::fmt::Display;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc_common::Part;
+use serde::{Deserialize, Serialize};
+
+/// Default location of the submission history, relative to the working
+/// directory.
+pub const DEFAULT_PATH: &str = "submissions.toml";
+
+/// How the server judged a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// An answer was submitted too recently, try again after this long.
+    Wait(Duration),
+    /// The part is not open for answers, e.g. because it was solved already.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Name of the outcome in the history, for outcomes that are kept there.
+    fn name(self) -> Option<&'static str> {
+        match self {
+            Outcome::Correct => Some("correct"),
+            Outcome::TooHigh => Some("too high"),
+            Outcome::TooLow => Some("too low"),
+            Outcome::Wrong => Some("wrong"),
+            Outcome::Wait(_) | Outcome::WrongLevel => None,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        match name {
+            "correct" => Some(Outcome::Correct),
+            "too high" => Some(Outcome::TooHigh),
+            "too low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "that's the right answer"),
+            Outcome::TooHigh => write!(f, "that's not the right answer, it is too high"),
+            Outcome::TooLow => write!(f, "that's not the right answer, it is too low"),
+            Outcome::Wrong => write!(f, "that's not the right answer"),
+            Outcome::Wait(wait) => write!(f, "an answer was submitted too recently, wait {}s before trying again", wait.as_secs()),
+            Outcome::WrongLevel => write!(f, "this part cannot be answered, maybe it was solved already"),
+        }
+    }
+}
+
+/// Work out the outcome of a submission from the page the server responds
+/// with, or `None` when the page is not recognised.
+pub fn parse_response(html: &str) -> Option<Outcome> {
+    // Only look at the message, not at the navigation around it
+    let message = match (html.find("<article>"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    if message.contains("That's the right answer") {
+        Some(Outcome::Correct)
+    } else if message.contains("That's not the right answer") {
+        if message.contains("answer is too high") {
+            Some(Outcome::TooHigh)
+        } else if message.contains("answer is too low") {
+            Some(Outcome::TooLow)
+        } else {
+            Some(Outcome::Wrong)
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Some(Outcome::Wait(parse_wait(message).unwrap_or(Duration::from_secs(60))))
+    } else if message.contains("You don't seem to be solving the right level") {
+        Some(Outcome::WrongLevel)
+    } else {
+        None
+    }
+}
+
+/// Parse the time left in a message like "You have 1m 5s left to wait".
+fn parse_wait(message: &str) -> Option<Duration> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = start + message[start..].find(" left to wait")?;
+
+    message[start..end].split_whitespace()
+        .map(|amount| match amount.split_at(amount.len() - 1) {
+            (n, "m") => n.parse::<u64>().ok().map(|n| n * 60),
+            (n, "s") => n.parse::<u64>().ok(),
+            _ => None,
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// History of the answers submitted to the server and how they were judged,
+/// keyed by day, part and input hash like the answer database. Used to avoid
+/// submitting answers that are known to be wrong.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct History {
+    submissions: Vec<Submission>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Submission {
+    day: u8,
+    part: Part,
+    input: String,
+    answer: String,
+    outcome: Outcome,
+}
+
+/// On-disk representation of the history.
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct HistoryFile {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<SubmissionEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SubmissionEntry {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: String,
+    outcome: String,
+}
+
+impl History {
+    /// Load the history at `path`. A missing file is an empty history.
+    pub fn load(path: &Path) -> Result<History, String> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(e) => return Err(format!("could not read {}: {}", path.display(), e)),
+        };
+
+        let file: HistoryFile = toml::from_str(&contents)
+            .map_err(|e| format!("invalid submission history {}: {}", path.display(), e))?;
+
+        let mut history = History::default();
+        for entry in file.submissions {
+            let part = match entry.part {
+                1 => Part::One,
+                2 => Part::Two,
+                p => return Err(format!("invalid part {} in submission history {}", p, path.display())),
+            };
+            let Some(outcome) = Outcome::from_name(&entry.outcome) else {
+                return Err(format!("invalid outcome `{}` in submission history {}", entry.outcome, path.display()));
+            };
+            history.submissions.push(Submission { day: entry.day, part, input: entry.input, answer: entry.answer, outcome });
+        }
+
+        Ok(history)
+    }
+
+    /// Write the history to `path`, in the order the answers were submitted.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let file = HistoryFile {
+            submissions: self.submissions.iter()
+                .map(|s| SubmissionEntry {
+                    day: s.day,
+                    part: s.part.number(),
+                    input: s.input.clone(),
+                    answer: s.answer.clone(),
+                    outcome: s.outcome.name().unwrap_or_default().to_owned(),
+                })
+                .collect(),
+        };
+
+        let contents = toml::to_string(&file)
+            .map_err(|e| format!("could not serialize submission history: {}", e))?;
+        std::fs::write(path, contents)
+            .map_err(|e| format!("could not write {}: {}", path.display(), e))
+    }
+
+    /// Record the outcome of submitting `answer`. Outcomes that say nothing
+    /// about the answer, like having to wait, are not kept.
+    pub fn insert(&mut self, day: u8, part: Part, hash: &str, answer: &str, outcome: Outcome) {
+        if outcome.name().is_some() {
+            self.submissions.push(Submission { day, part, input: hash.to_owned(), answer: answer.to_owned(), outcome });
+        }
+    }
+
+    /// The answer that was accepted for `part` of `day` with the input hashing
+    /// to `hash`, if any.
+    pub fn accepted(&self, day: u8, part: Part, hash: &str) -> Option<&str> {
+        self.matching(day, part, hash)
+            .find(|s| s.outcome == Outcome::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Why `answer` is known to be wrong, if it is: it was rejected before, or
+    /// it is a number beyond an earlier answer that was too high or too low.
+    pub fn known_wrong(&self, day: u8, part: Part, hash: &str, answer: &str) -> Option<String> {
+        let number = answer.trim().parse::<i128>().ok();
+        self.matching(day, part, hash).find_map(|s| {
+            let bound = s.answer.trim().parse::<i128>().ok();
+            match (s.outcome, number, bound) {
+                (Outcome::Correct, ..) => None,
+                _ if s.answer == answer => Some(format!("`{}` was rejected before: {}", answer, s.outcome)),
+                (Outcome::TooHigh, Some(n), Some(b)) if n >= b => Some(format!("`{}` was too high, so `{}` is too", s.answer, answer)),
+                (Outcome::TooLow, Some(n), Some(b)) if n <= b => Some(format!("`{}` was too low, so `{}` is too", s.answer, answer)),
+                _ => None,
+            }
+        })
+    }
+
+    fn matching(&self, day: u8, part: Part, hash: &str) -> impl Iterator<Item = &Submission> {
+        let hash = hash.to_owned();
+        self.submissions.iter().filter(move |s| s.day == day && s.part == part && s.input == hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outcome_from_response() {
+        let page = |message: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", message);
+
+        assert_eq!(
+            parse_response(&page("That's the right answer!  You are one gold star closer to collecting enough star fruit.")),
+            Some(Outcome::Correct),
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck, ...")),
+            Some(Outcome::TooHigh),
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too low.  Please wait one minute.")),
+            Some(Outcome::TooLow),
+        );
+        assert_eq!(parse_response(&page("That's not the right answer.  If you're stuck, ...")), Some(Outcome::Wrong));
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.")),
+            Some(Outcome::Wait(Duration::from_secs(65))),
+        );
+        assert_eq!(
+            parse_response(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+            Some(Outcome::WrongLevel),
+        );
+        assert_eq!(parse_response(&page("Something else")), None);
+    }
+
+    #[test]
+    fn known_wrong_answers() {
+        let mut history = History::default();
+        history.insert(1, Part::One, "abc", "500", Outcome::TooHigh);
+        history.insert(1, Part::One, "abc", "100", Outcome::TooLow);
+        history.insert(1, Part::One, "abc", "abc", Outcome::Wrong);
+        history.insert(1, Part::One, "abc", "300", Outcome::Wait(Duration::from_secs(5)));
+
+        assert!(history.known_wrong(1, Part::One, "abc", "500").is_some());
+        assert!(history.known_wrong(1, Part::One, "abc", "600").is_some());
+        assert!(history.known_wrong(1, Part::One, "abc", "99").is_some());
+        assert!(history.known_wrong(1, Part::One, "abc", "abc").is_some());
+        assert_eq!(history.known_wrong(1, Part::One, "abc", "300"), None);
+        assert_eq!(history.known_wrong(1, Part::Two, "abc", "500"), None);
+        assert_eq!(history.known_wrong(1, Part::One, "def", "500"), None);
+
+        assert_eq!(history.accepted(1, Part::One, "abc"), None);
+        history.insert(1, Part::One, "abc", "300", Outcome::Correct);
+        assert_eq!(history.accepted(1, Part::One, "abc"), Some("300"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc2022-submissions-{}.toml", std::process::id()));
+
+        let mut history = History::default();
+        history.insert(10, Part::Two, "abc", "EHZFZHCZ", Outcome::Wrong);
+        history.insert(1, Part::One, "abc", "24000", Outcome::TooLow);
+        history.insert(1, Part::One, "abc", "70698", Outcome::Correct);
+        history.save(&path).unwrap();
+
+        let loaded = History::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, Ok(history));
+    }
+}
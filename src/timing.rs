@@ -0,0 +1,68 @@
+use std::time::{Duration, Instant};
+
+/// Timing of a function over one or more runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timing {
+    pub min: Duration,
+    pub median: Duration,
+}
+
+/// Run `f` `repeat` times (at least once), returning the result of the last run
+/// and the timing over all of them.
+pub fn measure<T>(repeat: usize, mut f: impl FnMut() -> T) -> (T, Timing) {
+    let mut times = Vec::with_capacity(repeat.max(1));
+    let mut result = None;
+    for _ in 0..repeat.max(1) {
+        // Drop the previous result first, so it is not part of the timing
+        drop(result.take());
+        let start = Instant::now();
+        result = Some(f());
+        times.push(start.elapsed());
+    }
+
+    (result.unwrap(), Timing::from_times(times))
+}
+
+impl Timing {
+    /// Timing of the given run times, which may not be empty.
+    pub fn from_times(mut times: Vec<Duration>) -> Timing {
+        times.sort_unstable();
+        let mid = times.len() / 2;
+        let median = if times.len().is_multiple_of(2) {
+            (times[mid - 1] + times[mid]) / 2
+        } else {
+            times[mid]
+        };
+
+        Timing { min: times[0], median }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_and_median() {
+        let ms = |ms: &[u64]| ms.iter().map(|&ms| Duration::from_millis(ms)).collect::<Vec<Duration>>();
+
+        assert_eq!(
+            Timing::from_times(ms(&[5, 1, 3])),
+            Timing { min: Duration::from_millis(1), median: Duration::from_millis(3) },
+        );
+        assert_eq!(
+            Timing::from_times(ms(&[4, 8, 2, 6])),
+            Timing { min: Duration::from_millis(2), median: Duration::from_millis(5) },
+        );
+    }
+
+    #[test]
+    fn repeats() {
+        let mut runs = 0;
+        let (result, _) = measure(3, || { runs += 1; runs });
+        assert_eq!((result, runs), (3, 3));
+
+        let (result, _) = measure(0, || "once");
+        assert_eq!(result, "once");
+    }
+}
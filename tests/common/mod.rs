@@ -0,0 +1,117 @@
+//! Helpers shared by the integration tests.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use aoc2022::config::Config;
+
+/// Request received by a [`StubServer`].
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+}
+
+/// Response of a [`StubServer`]: status, extra headers and body.
+pub type Response = (u16, Vec<(&'static str, String)>, String);
+
+/// Minimal HTTP server on localhost standing in for the Advent of Code server.
+/// Every request is answered by a handler, and kept for inspection.
+pub struct StubServer {
+    pub url: String,
+    pub requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StubServer {
+    pub fn start(handler: impl Fn(&Request) -> Response + Send + 'static) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let received = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else {
+                    continue;
+                };
+                if let Some(request) = read_request(&stream) {
+                    let response = handler(&request);
+                    received.lock().unwrap().push(request);
+                    write_response(stream, response);
+                }
+            }
+        });
+
+        StubServer { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Configuration pointing at this server, with a fresh cache directory and
+    /// without waiting between requests.
+    pub fn config(&self, name: &str) -> Config {
+        let cache_dir = temp_dir(name);
+        Config {
+            session: Some(String::from("stub-session")),
+            base_url: Some(self.url.clone()),
+            cache_dir: Some(cache_dir),
+            request_interval: Some(0.0),
+        }
+    }
+}
+
+/// Empty temporary directory unique to this test run.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc2022-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let (method, path) = (parts.next()?.to_owned(), parts.next()?.to_owned());
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        match line.trim_end().split_once(": ") {
+            Some((name, value)) => headers.push((name.to_owned(), value.to_owned())),
+            None => break,
+        }
+    }
+
+    let mut request = Request { method, path, headers, body: String::new() };
+    let length = request.header("Content-Length").and_then(|l| l.parse::<usize>().ok()).unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+    request.body = String::from_utf8(body).ok()?;
+    Some(request)
+}
+
+fn write_response(mut stream: TcpStream, (status, headers, body): Response) {
+    let mut response = format!("HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n", status, body.len());
+    for (name, value) in headers {
+        response.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    response.push_str("\r\n");
+    response.push_str(&body);
+    let _ = stream.write_all(response.as_bytes());
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use aoc2022::client::Client;
+use aoc2022::config::Config;
+use common::StubServer;
+
+const PAGE: &str = "<p>For example:</p>\n<pre><code>1\n2\n3\n</code></pre>";
+
+fn puzzle_server() -> StubServer {
+    StubServer::start(|request| match (request.header("Cookie"), request.path.as_str()) {
+        (Some("session=stub-session"), "/2022/day/1/input") => (200, vec![], String::from("100\n200\n")),
+        (Some("session=stub-session"), "/2022/day/1") => (200, vec![], String::from(PAGE)),
+        (Some("session=stub-session"), _) => (404, vec![], String::from("404 Not Found")),
+        _ => (400, vec![], String::from("Puzzle inputs differ by user.  Please log in to get your puzzle input.")),
+    })
+}
+
+#[test]
+fn fetches_and_caches_input() {
+    let server = puzzle_server();
+    let config = server.config("fetch-cache");
+    let client = Client::new(&config).unwrap();
+
+    assert_eq!(client.input(1, false).as_deref(), Ok("100\n200\n"));
+    assert_eq!(client.sample(1, false).as_deref(), Ok("1\n2\n3\n"));
+    assert_eq!(std::fs::read_to_string(client.day_dir(1).join("input")).unwrap(), "100\n200\n");
+    assert!(client.day_dir(1).ends_with("2022/day01"));
+
+    // Served from the cache the second time, unless refreshed
+    assert_eq!(client.input(1, false).as_deref(), Ok("100\n200\n"));
+    assert_eq!(server.requests().len(), 2);
+    assert_eq!(client.input(1, true).as_deref(), Ok("100\n200\n"));
+    assert_eq!(server.requests().len(), 3);
+
+    // The cache does not need a session token, only requests do
+    let client = Client::new(&Config { session: None, ..config.clone() }).unwrap();
+    assert_eq!(client.input(1, false).as_deref(), Ok("100\n200\n"));
+    assert!(client.input(1, true).unwrap_err().contains("no session token"));
+    assert_eq!(server.requests().len(), 3);
+
+    let request = &server.requests()[0];
+    assert_eq!((request.method.as_str(), request.path.as_str()), ("GET", "/2022/day/1/input"));
+    assert!(request.header("User-Agent").is_some_and(|agent| agent.contains("aoc2022")));
+
+    std::fs::remove_dir_all(config.cache_dir.unwrap()).unwrap();
+}
+
+#[test]
+fn reports_server_errors() {
+    let server = puzzle_server();
+    let config = server.config("fetch-errors");
+
+    let client = Client::new(&config).unwrap();
+    assert!(client.input(2, false).unwrap_err().contains("not available"));
+
+    let client = Client::new(&Config { session: Some(String::from("wrong")), ..config.clone() }).unwrap();
+    assert!(client.input(1, false).unwrap_err().contains("rejected the session token"));
+
+    // Nothing is cached for failed requests
+    assert!(!client.day_dir(1).join("input").exists());
+    let client = Client::new(&Config { session: None, ..config.clone() }).unwrap();
+    assert!(client.input(1, false).unwrap_err().contains("no session token"));
+
+    std::fs::remove_dir_all(config.cache_dir.unwrap()).unwrap();
+}
+
+#[test]
+fn spaces_out_requests() {
+    let server = puzzle_server();
+    let config = Config { request_interval: Some(0.2), ..server.config("fetch-interval") };
+    let client = Client::new(&config).unwrap();
+
+    let start = Instant::now();
+    client.input(1, true).unwrap();
+    client.input(1, true).unwrap();
+    client.input(1, true).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(400));
+
+    std::fs::remove_dir_all(config.cache_dir.unwrap()).unwrap();
+}
+
+#[test]
+fn retries_after_too_many_requests() {
+    // Rate limit the first request only
+    let limited = AtomicBool::new(true);
+    let server = StubServer::start(move |_| match limited.swap(false, Ordering::Relaxed) {
+        true => (429, vec![("Retry-After", String::from("1"))], String::new()),
+        false => (200, vec![], String::from("42\n")),
+    });
+    let config = server.config("fetch-retry");
+    let client = Client::new(&config).unwrap();
+
+    assert_eq!(client.input(3, false).as_deref(), Ok("42\n"));
+    assert_eq!(server.requests().len(), 2);
+
+    std::fs::remove_dir_all(config.cache_dir.unwrap()).unwrap();
+}
@@ -0,0 +1,58 @@
+use std::path::Path;
+
+use aoc2022::answers::{self, Answers};
+use aoc2022::registry::{self, DAYS};
+use aoc_common::Part;
+
+#[test]
+fn days_are_registered_in_order() {
+    assert!(DAYS.windows(2).all(|w| w[0].day() < w[1].day()));
+    assert!(DAYS.iter().all(|d| (1..=25).contains(&d.day())));
+}
+
+#[test]
+fn days_can_be_looked_up() {
+    for day in DAYS {
+        assert_eq!(registry::get(day.day()).map(|d| d.title()), Some(day.title()));
+    }
+}
+
+#[test]
+fn registered_days_match_the_answer_database() {
+    let answers = Answers::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join(answers::DEFAULT_PATH)).unwrap();
+
+    let mut checked = 0;
+    for day in DAYS {
+        // Days without an input compiled in only check their sample, in their
+        // own tests
+        let Some(input) = day.input() else {
+            continue;
+        };
+        let parsed = day.parse_input(input)
+            .unwrap_or_else(|e| panic!("Invalid input for day {}: {}", day.day(), e));
+
+        let hash = answers::input_hash(input);
+        for part in Part::ALL {
+            if let Some(expected) = answers.get(day.day(), part, &hash) {
+                assert_eq!(day.solve(part, parsed.as_ref()), expected, "challenge {}.{}", day.day(), part);
+                checked += 1;
+            }
+        }
+    }
+    assert!(checked > 0 || DAYS.iter().all(|d| d.input().is_none()));
+}
+
+#[test]
+fn stars_count_the_answers_of_registered_inputs() {
+    let mut answers = Answers::default();
+    assert_eq!(registry::stars(&answers), 0);
+
+    let Some(day) = DAYS.iter().find(|d| d.input().is_some()) else {
+        return;
+    };
+    let hash = answers::input_hash(day.input().unwrap());
+    answers.insert(day.day(), Part::One, &hash, "1");
+    answers.insert(day.day(), Part::Two, "not the input", "2");
+    answers.insert(registry::SUMMARY.0, registry::SUMMARY.1, &hash, "3");
+    assert_eq!(registry::stars(&answers), 1);
+}
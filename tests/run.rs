@@ -0,0 +1,61 @@
+use std::path::Path;
+use std::process::{Command, Output};
+
+use aoc2022::answers::{self, Answers};
+use aoc2022::registry::DAYS;
+use aoc_common::Part;
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc2022"))
+        .arg("run")
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn days_without_input_are_skipped_in_ranges() {
+    // Two days in a row without an input keep this from solving anything
+    let no_input = |day: u8| DAYS.iter().find(|d| d.day() == day).is_some_and(|d| d.input().is_none());
+    let Some(day) = (1..25).find(|&day| no_input(day) && no_input(day + 1)) else {
+        return;
+    };
+
+    let output = run(&[&format!("{}..={}", day, day + 1), "--format", "plain"]);
+    let (stdout, stderr) = (String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+    assert!(output.status.success(), "{}", stderr);
+    assert!(stderr.contains(&format!("note: skipping day {}, it has no input", day)));
+    assert!(stdout.contains(&format!("day {} part 1: (no-input)", day)));
+
+    let output = run(&[&day.to_string()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains(&format!("error: no input for day {}", day)));
+}
+
+#[test]
+fn last_day_counts_the_stars_of_all_days() {
+    let Some(input) = DAYS.iter().find(|d| d.day() == 25).and_then(|d| d.input()) else {
+        return;
+    };
+
+    let path = std::env::temp_dir().join(format!("aoc2022-stars-{}.toml", std::process::id()));
+    let mut database = Answers::default();
+    database.insert(25, Part::One, &answers::input_hash(input), "20-=0=02=-21=00-02=2");
+    database.save(&path).unwrap();
+    let path = path.to_str().unwrap();
+
+    // The stars of parts that are not run count as well
+    let output = run(&["25.2", "--answers", path, "--format", "csv"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.contains("1 of 49 stars collected, 48 to go"), "{}", stdout);
+    assert!(stdout.lines().any(|l| l.starts_with("25,2,") && l.ends_with(",summary")), "{}", stdout);
+
+    // The summary is not an answer to verify or record
+    let output = run(&["25", "--answers", path, "--record", "--format", "plain"]);
+    let loaded = Answers::load(Path::new(path));
+    std::fs::remove_file(path).unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Verified 1 answers: 1 passed"));
+    assert_eq!(loaded, Ok(database));
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use std::path::Path;
+use std::process::{Command, Output};
+use std::time::Duration;
+
+use aoc2022::client::Client;
+use aoc2022::submissions::Outcome;
+use aoc_common::Part;
+use common::StubServer;
+
+/// Server accepting `24000` as the only answer, and judging the others by how
+/// far off they are.
+fn answer_server() -> StubServer {
+    StubServer::start(|request| {
+        let answer = request.body.split('&')
+            .find_map(|field| field.strip_prefix("answer="))
+            .and_then(|answer| answer.parse::<i64>().ok());
+        let message = match answer {
+            Some(24000) => "That's the right answer!  You are one gold star closer to collecting enough star fruit.",
+            Some(n) if n > 24000 => "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+            Some(_) => "That's not the right answer; your answer is too low.  Please wait one minute before trying again.",
+            None => "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 42s left to wait.",
+        };
+        (200, vec![], format!("<html><main>\n<article><p>{}</p></article>\n</main></html>", message))
+    })
+}
+
+#[test]
+fn submits_answers() {
+    let server = answer_server();
+    let config = server.config("submit-client");
+    let client = Client::new(&config).unwrap();
+
+    assert_eq!(client.submit(1, Part::One, "24000"), Ok(Outcome::Correct));
+    assert_eq!(client.submit(1, Part::Two, "99999"), Ok(Outcome::TooHigh));
+    assert_eq!(client.submit(1, Part::Two, "wait"), Ok(Outcome::Wait(Duration::from_secs(42))));
+
+    let request = &server.requests()[1];
+    assert_eq!((request.method.as_str(), request.path.as_str()), ("POST", "/2022/day/1/answer"));
+    assert_eq!(request.header("Cookie"), Some("session=stub-session"));
+    assert_eq!(request.body, "level=2&answer=99999");
+
+    std::fs::remove_dir_all(config.cache_dir.unwrap()).unwrap();
+}
+
+/// Run `aoc2022 submit` for challenge 1.1 on the sample, against `server`,
+/// keeping all files in `dir`.
+fn run_submit(server: &StubServer, dir: &Path, extra: &[&str]) -> (Output, String) {
+    let sample = Path::new(env!("CARGO_MANIFEST_DIR")).join("day01").join("sample");
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc2022"))
+        .args(["submit", "1", "1", "--input"])
+        .arg(sample)
+        .arg("--answers").arg(dir.join("answers.toml"))
+        .arg("--history").arg(dir.join("submissions.toml"))
+        .args(extra)
+        .env("AOC_CONFIG", dir.join("config.toml"))
+        .env("AOC_SESSION", "stub-session")
+        .env("AOC_BASE_URL", &server.url)
+        .env("AOC_CACHE_DIR", dir.join("cache"))
+        .output()
+        .unwrap();
+    let text = String::from_utf8_lossy(&output.stdout).into_owned() + &String::from_utf8_lossy(&output.stderr);
+    (output, text)
+}
+
+#[test]
+fn never_resubmits_wrong_answers() {
+    let server = answer_server();
+    let dir = common::temp_dir("submit-history");
+    std::fs::write(dir.join("config.toml"), "request_interval = 0\n").unwrap();
+
+    let (output, text) = run_submit(&server, &dir, &["--answer", "30000"]);
+    assert!(!output.status.success());
+    assert!(text.contains("too high"), "{}", text);
+    assert_eq!(server.requests().len(), 1);
+
+    // Known to be wrong, so not submitted again
+    for answer in ["30000", "35000"] {
+        let (output, text) = run_submit(&server, &dir, &["--answer", answer]);
+        assert!(!output.status.success());
+        assert!(text.contains("not submitting"), "{}", text);
+    }
+    assert_eq!(server.requests().len(), 1);
+
+    // The solution is correct, and ends up in the answer database
+    let (output, text) = run_submit(&server, &dir, &[]);
+    assert!(output.status.success(), "{}", text);
+    assert!(text.contains("right answer"), "{}", text);
+    assert!(std::fs::read_to_string(dir.join("answers.toml")).unwrap().contains("answer = \"24000\""));
+
+    let (output, text) = run_submit(&server, &dir, &[]);
+    assert!(output.status.success());
+    assert!(text.contains("already answered"), "{}", text);
+    assert_eq!(server.requests().len(), 2);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}